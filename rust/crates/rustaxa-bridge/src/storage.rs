@@ -37,6 +37,10 @@ mod ffi {
         ) -> Result<Vec<BlockRlp>>;
         fn get_nonfinalized_dag_blocks(&self) -> Result<Vec<LevelBlocks>>;
         fn get_proposal_period_for_dag_level(&self, level: u64) -> Result<u64>;
+
+        fn prune_dag_blocks_before(&self, period: u64) -> Result<u64>;
+        fn prune_to_last_n_periods(&self, n: u64) -> Result<u64>;
+        fn dag_blocks_db_size(&self) -> Result<u64>;
     }
 }
 
@@ -130,4 +134,20 @@ impl Storage {
             .map(|opt| opt.unwrap_or(0))
             .map_err(|e| anyhow::anyhow!(e))
     }
+
+    fn prune_dag_blocks_before(&self, period: u64) -> Result<u64, anyhow::Error> {
+        self.0
+            .prune_dag_blocks_before(period)
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    fn prune_to_last_n_periods(&self, n: u64) -> Result<u64, anyhow::Error> {
+        self.0
+            .prune_to_last_n_periods(n)
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    fn dag_blocks_db_size(&self) -> Result<u64, anyhow::Error> {
+        self.0.dag_blocks_db_size().map_err(|e| anyhow::anyhow!(e))
+    }
 }
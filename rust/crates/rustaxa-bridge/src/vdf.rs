@@ -1,12 +1,26 @@
-use rustaxa_vdf::prover::{CancellationToken as InnerCancellationToken, WesolowskiProver};
+use rustaxa_vdf::prover::{
+    CancellationToken as InnerCancellationToken, JobId, ProverPool as InnerProverPool,
+    WesolowskiProver,
+};
 use rustaxa_vdf::vdf::{Solution as InnerSolution, WesolowskiVdf as InnerWesolowskiVdf};
-use rustaxa_vdf::verifier::WesolowskiVerifier;
+use rustaxa_vdf::verifier::{self, WesolowskiVerifier};
 
 // Wrapper types to satisfy Orphan Rule since we are bridging types from another crate
 pub struct WesolowskiVdf(InnerWesolowskiVdf);
 pub struct CancellationToken(InnerCancellationToken);
 pub struct Solution(InnerSolution);
 
+// Accumulator for batch verification. cxx cannot pass a slice of opaque
+// references, so the C++ side pushes `(vdf, solution)` pairs one at a time and
+// then calls `run`.
+#[derive(Default)]
+pub struct BatchVerifier {
+    vdfs: Vec<InnerWesolowskiVdf>,
+    solutions: Vec<InnerSolution>,
+}
+
+pub struct ProverPool(InnerProverPool);
+
 #[cxx::bridge(namespace = "rustaxa::vdf")]
 mod ffi {
     extern "Rust" {
@@ -26,9 +40,6 @@ mod ffi {
         fn make_solution(proof: &[u8], output: &[u8]) -> Box<Solution>;
 
         fn make_cancellation_token() -> Box<CancellationToken>;
-        unsafe fn make_cancellation_token_with_atomic(
-            atomic_ptr: *const bool,
-        ) -> Box<CancellationToken>;
         fn cancellation_token_cancel(token: &CancellationToken);
 
         fn prove(vdf: &WesolowskiVdf, cancelled: &CancellationToken) -> Box<Solution>;
@@ -36,6 +47,97 @@ mod ffi {
 
         fn solution_get_proof(solution: &Solution) -> &[u8];
         fn solution_get_output(solution: &Solution) -> &[u8];
+
+        type ProverPool;
+        fn make_prover_pool(num_threads: usize) -> Box<ProverPool>;
+        fn prover_pool_submit(
+            pool: &ProverPool,
+            lambda: u32,
+            time_bits: u32,
+            input: &[u8],
+            modulus: &[u8],
+        ) -> u64;
+        fn prover_pool_poll(pool: &ProverPool, id: u64) -> Box<Solution>;
+        fn prover_pool_cancel(pool: &ProverPool, id: u64);
+
+        type BatchVerifier;
+        fn make_batch_verifier() -> Box<BatchVerifier>;
+        fn push_pair(
+            self: &mut BatchVerifier,
+            lambda: u32,
+            time_bits: u32,
+            input: &[u8],
+            modulus: &[u8],
+            proof: &[u8],
+            output: &[u8],
+        );
+        fn run(self: &BatchVerifier) -> bool;
+    }
+}
+
+pub fn make_prover_pool(num_threads: usize) -> Box<ProverPool> {
+    Box::new(ProverPool(InnerProverPool::new(num_threads)))
+}
+
+pub fn prover_pool_submit(
+    pool: &ProverPool,
+    lambda: u32,
+    time_bits: u32,
+    input: &[u8],
+    modulus: &[u8],
+) -> JobId {
+    pool.0.submit(InnerWesolowskiVdf::new(
+        lambda,
+        time_bits,
+        input.to_vec(),
+        modulus.to_vec(),
+    ))
+}
+
+pub fn prover_pool_poll(pool: &ProverPool, id: JobId) -> Box<Solution> {
+    Box::new(Solution(pool.0.poll(id).unwrap_or(InnerSolution {
+        first: vec![],
+        second: vec![],
+    })))
+}
+
+pub fn prover_pool_cancel(pool: &ProverPool, id: JobId) {
+    pool.0.cancel(id);
+}
+
+pub fn make_batch_verifier() -> Box<BatchVerifier> {
+    Box::new(BatchVerifier::default())
+}
+
+impl BatchVerifier {
+    fn push_pair(
+        &mut self,
+        lambda: u32,
+        time_bits: u32,
+        input: &[u8],
+        modulus: &[u8],
+        proof: &[u8],
+        output: &[u8],
+    ) {
+        self.vdfs.push(InnerWesolowskiVdf::new(
+            lambda,
+            time_bits,
+            input.to_vec(),
+            modulus.to_vec(),
+        ));
+        self.solutions.push(InnerSolution {
+            first: proof.to_vec(),
+            second: output.to_vec(),
+        });
+    }
+
+    fn run(&self) -> bool {
+        let pairs: Vec<(&InnerWesolowskiVdf, &InnerSolution)> = self
+            .vdfs
+            .iter()
+            .zip(self.solutions.iter())
+            .collect();
+        verifier::verify_batch(&pairs)
     }
 }
 
@@ -59,10 +161,6 @@ pub fn make_cancellation_token() -> Box<CancellationToken> {
     Box::new(CancellationToken(InnerCancellationToken::new()))
 }
 
-pub fn make_cancellation_token_with_atomic(atomic_ptr: *const bool) -> Box<CancellationToken> {
-    Box::new(CancellationToken(InnerCancellationToken::from_atomic_ptr(atomic_ptr)))
-}
-
 pub fn cancellation_token_cancel(token: &CancellationToken) {
     token.0.cancel();
 }
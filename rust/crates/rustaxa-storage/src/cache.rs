@@ -0,0 +1,206 @@
+use anyhow::Result;
+use ethereum_types::H256;
+use rustaxa_types::DagBlock;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use crate::db::DbReader;
+use crate::DagRepository;
+
+/// A tiny capacity-bounded LRU map.
+///
+/// The DAG caches hold at most a few thousand entries, so a vector tracking
+/// recency order keeps the implementation dependency-free and easy to reason
+/// about; the most-recently-used key sits at the end.
+struct Lru<K: Eq + Hash + Clone, V> {
+    capacity: usize,
+    map: HashMap<K, V>,
+    order: Vec<K>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Lru<K, V> {
+    fn new(capacity: usize) -> Self {
+        Lru {
+            capacity: capacity.max(1),
+            map: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    fn touch(&mut self, key: &K) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let k = self.order.remove(pos);
+            self.order.push(k);
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<V> {
+        if self.map.contains_key(key) {
+            self.touch(key);
+            self.map.get(key).cloned()
+        } else {
+            None
+        }
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        if self.map.insert(key.clone(), value).is_some() {
+            self.touch(&key);
+        } else {
+            self.order.push(key);
+            while self.order.len() > self.capacity {
+                let evict = self.order.remove(0);
+                self.map.remove(&evict);
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &K) {
+        if self.map.remove(key).is_some() {
+            if let Some(pos) = self.order.iter().position(|k| k == key) {
+                self.order.remove(pos);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+}
+
+/// A read-through LRU cache over [`DagRepository`].
+///
+/// `dag_block` otherwise re-parses RLP on every call and chains up to three DB
+/// lookups for a finalized block; `blocks_by_level` re-reads the level index.
+/// This wrapper serves hot-path DAG queries during block proposal and syncing
+/// from memory, populating the caches on miss. Entries should be invalidated via
+/// [`CachedDagRepository::invalidate_level`] when a level is finalized.
+pub struct CachedDagRepository<D: DbReader> {
+    inner: DagRepository<D>,
+    blocks: Mutex<Lru<H256, DagBlock>>,
+    levels: Mutex<Lru<u64, Vec<H256>>>,
+}
+
+impl<D: DbReader> CachedDagRepository<D> {
+    /// Wraps `db` with decoded-block and level caches bounded to `capacity`
+    /// entries each.
+    pub fn new(db: Arc<D>, capacity: usize) -> Self {
+        CachedDagRepository {
+            inner: DagRepository::new(db),
+            blocks: Mutex::new(Lru::new(capacity)),
+            levels: Mutex::new(Lru::new(capacity)),
+        }
+    }
+
+    /// The underlying uncached repository.
+    pub fn inner(&self) -> &DagRepository<D> {
+        &self.inner
+    }
+
+    /// Returns a decoded block, consulting the block cache before the database.
+    pub fn dag_block(&self, block: H256) -> Result<DagBlock> {
+        if let Some(cached) = self.blocks.lock().unwrap().get(&block) {
+            return Ok(cached);
+        }
+        let decoded = self.inner.dag_block(block)?;
+        self.blocks.lock().unwrap().put(block, decoded.clone());
+        Ok(decoded)
+    }
+
+    /// Returns the hashes at `level`, consulting the level cache before the
+    /// database.
+    pub fn blocks_by_level(&self, level: u64) -> Result<Vec<H256>> {
+        if let Some(cached) = self.levels.lock().unwrap().get(&level) {
+            return Ok(cached);
+        }
+        let hashes = self.inner.blocks_by_level(level)?;
+        self.levels.lock().unwrap().put(level, hashes.clone());
+        Ok(hashes)
+    }
+
+    /// Drops the cached level entry and every block it referenced, e.g. once the
+    /// level has been finalized and moved into period data.
+    pub fn invalidate_level(&self, level: u64) {
+        let hashes = self.levels.lock().unwrap().get(&level);
+        if let Some(hashes) = hashes {
+            let mut blocks = self.blocks.lock().unwrap();
+            for hash in hashes {
+                blocks.remove(&hash);
+            }
+        }
+        self.levels.lock().unwrap().remove(&level);
+    }
+
+    /// Clears both caches entirely.
+    pub fn clear(&self) {
+        self.blocks.lock().unwrap().clear();
+        self.levels.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryDb;
+    use crate::Column;
+    use rlp::RlpStream;
+
+    fn dummy_block_rlp(level: u64) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(8);
+        stream.append(&H256::zero());
+        stream.append(&level);
+        stream.append(&123u64);
+        stream.append(&vec![1u8, 2, 3]);
+        stream.begin_list(0);
+        stream.begin_list(0);
+        stream.append(&vec![0u8; 65]);
+        stream.append(&1000u64);
+        stream.out().to_vec()
+    }
+
+    #[test]
+    fn test_block_cache_serves_after_delete() {
+        let db = Arc::new(MemoryDb::new());
+        let hash = H256::random();
+        db.put(Column::DagBlocks, hash.as_bytes(), &dummy_block_rlp(10));
+
+        let repo = CachedDagRepository::new(db.clone(), 16);
+        assert_eq!(repo.dag_block(hash).unwrap().level, 10);
+
+        // Remove the backing row; the cached copy must still be served.
+        db.delete(Column::DagBlocks, hash.as_bytes());
+        assert_eq!(repo.dag_block(hash).unwrap().level, 10);
+    }
+
+    #[test]
+    fn test_invalidate_level_drops_blocks() {
+        let db = Arc::new(MemoryDb::new());
+        let hash = H256::random();
+        db.put(Column::DagBlocks, hash.as_bytes(), &dummy_block_rlp(5));
+        let mut stream = RlpStream::new_list(1);
+        stream.append(&hash);
+        db.put(Column::DagBlocksLevel, &5u64.to_le_bytes(), &stream.out());
+
+        let repo = CachedDagRepository::new(db.clone(), 16);
+        repo.blocks_by_level(5).unwrap();
+        repo.dag_block(hash).unwrap();
+
+        repo.invalidate_level(5);
+        db.delete(Column::DagBlocks, hash.as_bytes());
+        assert!(repo.dag_block(hash).is_err());
+    }
+
+    #[test]
+    fn test_lru_evicts_oldest() {
+        let mut lru: Lru<u64, u64> = Lru::new(2);
+        lru.put(1, 1);
+        lru.put(2, 2);
+        lru.get(&1); // 1 becomes most-recent
+        lru.put(3, 3); // evicts 2
+        assert_eq!(lru.get(&1), Some(1));
+        assert_eq!(lru.get(&2), None);
+        assert_eq!(lru.get(&3), Some(3));
+    }
+}
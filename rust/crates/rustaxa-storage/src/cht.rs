@@ -0,0 +1,155 @@
+//! Canonical level-hash-trie proofs for DAG light clients.
+//!
+//! Adapting Substrate's canonical-hash-trie idea, a contiguous range of
+//! `Column::DagBlocksLevel` entries is folded into a binary Merkle tree: each
+//! level's RLP-encoded `Vec<H256>` is hashed (keccak-256) into a leaf, and the
+//! leaves are combined pairwise up to a single root. Against a trusted root a
+//! peer can confirm that a `blocks_by_level` result is neither truncated nor
+//! padded, which is the primitive a DAG light-sync protocol needs.
+
+use ethereum_types::H256;
+
+/// keccak-256 of the concatenated parts, matching the hashing used elsewhere in
+/// the workspace.
+fn keccak256(parts: &[&[u8]]) -> H256 {
+    use tiny_keccak::{Hasher, Keccak};
+    let mut hasher = Keccak::v256();
+    for part in parts {
+        hasher.update(part);
+    }
+    let mut out = [0u8; 32];
+    hasher.finalize(&mut out);
+    H256(out)
+}
+
+/// Hashes a level's hash set into its Merkle leaf.
+pub fn level_leaf(hashes: &[H256]) -> H256 {
+    let mut stream = rlp::RlpStream::new_list(hashes.len());
+    for h in hashes {
+        stream.append(h);
+    }
+    keccak256(&[&stream.out()])
+}
+
+/// Combines two child nodes into their parent.
+fn combine(left: &H256, right: &H256) -> H256 {
+    keccak256(&[left.as_bytes(), right.as_bytes()])
+}
+
+/// Computes the Merkle root over `leaves`, duplicating the final node on odd
+/// layers (the conventional binary-tree padding).
+pub fn merkle_root(leaves: &[H256]) -> H256 {
+    if leaves.is_empty() {
+        return H256::zero();
+    }
+    let mut layer = leaves.to_vec();
+    while layer.len() > 1 {
+        let mut next = Vec::with_capacity(layer.len().div_ceil(2));
+        for pair in layer.chunks(2) {
+            let right = if pair.len() == 2 { &pair[1] } else { &pair[0] };
+            next.push(combine(&pair[0], right));
+        }
+        layer = next;
+    }
+    layer[0]
+}
+
+/// Builds the Merkle branch (sibling path) proving leaf `index`.
+pub fn merkle_branch(leaves: &[H256], mut index: usize) -> Vec<H256> {
+    let mut branch = Vec::new();
+    if leaves.is_empty() || index >= leaves.len() {
+        return branch;
+    }
+    let mut layer = leaves.to_vec();
+    while layer.len() > 1 {
+        let sibling = if index % 2 == 0 {
+            // Right sibling, duplicated when the layer is odd and this is last.
+            if index + 1 < layer.len() {
+                index + 1
+            } else {
+                index
+            }
+        } else {
+            index - 1
+        };
+        branch.push(layer[sibling]);
+        let mut next = Vec::with_capacity(layer.len().div_ceil(2));
+        for pair in layer.chunks(2) {
+            let right = if pair.len() == 2 { &pair[1] } else { &pair[0] };
+            next.push(combine(&pair[0], right));
+        }
+        layer = next;
+        index /= 2;
+    }
+    branch
+}
+
+/// Recomputes the root from a leaf and its branch, folding siblings in the same
+/// left/right order `merkle_branch` recorded them.
+fn fold_branch(mut node: H256, mut index: usize, branch: &[H256]) -> H256 {
+    for sibling in branch {
+        node = if index % 2 == 0 {
+            combine(&node, sibling)
+        } else {
+            combine(sibling, &node)
+        };
+        index /= 2;
+    }
+    node
+}
+
+/// Verifies that `hashes` is the genuine level set at `level` within a trie
+/// rooted at `root` and starting at level `start`. The leaf index is
+/// `level - start`.
+pub fn verify_level_set_proof(
+    root: H256,
+    start: u64,
+    level: u64,
+    hashes: &[H256],
+    branch: &[H256],
+) -> bool {
+    if level < start {
+        return false;
+    }
+    let index = (level - start) as usize;
+    let leaf = level_leaf(hashes);
+    fold_branch(leaf, index, branch) == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_level_root_is_leaf() {
+        let hashes = vec![H256::random(), H256::random()];
+        let leaf = level_leaf(&hashes);
+        assert_eq!(merkle_root(&[leaf]), leaf);
+    }
+
+    #[test]
+    fn test_proof_round_trips() {
+        let levels: Vec<Vec<H256>> = (0..5)
+            .map(|_| vec![H256::random(), H256::random()])
+            .collect();
+        let leaves: Vec<H256> = levels.iter().map(|l| level_leaf(l)).collect();
+        let root = merkle_root(&leaves);
+
+        let start = 100u64;
+        for (i, level_hashes) in levels.iter().enumerate() {
+            let branch = merkle_branch(&leaves, i);
+            let level = start + i as u64;
+            assert!(verify_level_set_proof(
+                root,
+                start,
+                level,
+                level_hashes,
+                &branch
+            ));
+            // Tampering with the returned set breaks verification.
+            let mut tampered = level_hashes.clone();
+            tampered.push(H256::random());
+            assert!(!verify_level_set_proof(root, start, level, &tampered, &branch));
+        }
+    }
+}
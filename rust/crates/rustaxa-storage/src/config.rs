@@ -1,6 +1,9 @@
 use anyhow::Result;
 use std::cmp::Ordering;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Arc;
 
 use crate::StorageError;
 
@@ -17,6 +20,30 @@ pub struct Config {
     pub db_write_buffer_size: usize,
     pub max_open_files: i32,
 
+    /// When set, the database is opened tuned for a one-shot bulk ingestion
+    /// (genesis replay / snapshot restore): auto-compaction is disabled and the
+    /// level-0 triggers are raised so writes never stall. Callers flush the
+    /// ingested data with [`Storage::finish_bulk_load`] and reopen in normal
+    /// mode afterwards.
+    pub bulk_load: bool,
+
+    /// When set, the read path records per-column Prometheus metrics (latency
+    /// histograms, get/iter counters, key/value-size summaries) through
+    /// [`Storage::metrics_registry`](crate::Storage::metrics_registry). Off by
+    /// default so the hot path stays allocation- and atomic-free.
+    pub enable_metrics: bool,
+
+    /// Per-column tuning overrides parsed from the TOML `[storage.columns]`
+    /// tables, keyed by [`Column`]. Columns absent from the map fall back to
+    /// their built-in [`Column::options`].
+    pub column_overrides: HashMap<Column, ColumnOptions>,
+
+    /// Shared "prune-below period" horizon. Entries in uint64-keyed columns
+    /// whose decoded period is strictly below this value are dropped during
+    /// compaction. A value of zero disables pruning entirely, so full-archive
+    /// nodes are unaffected; the node bumps it as finality advances.
+    pub prune_horizon: Arc<AtomicU64>,
+
     pub column_families: Vec<Column>,
 }
 
@@ -33,9 +60,133 @@ impl Config {
             max_total_wal_size: 1024 * 1024 * 1024, // 1GB
             db_write_buffer_size: 2 * 1024 * 1024 * 1024, // 2GB
             max_open_files: 256,
+            bulk_load: false,
+            enable_metrics: false,
+            column_overrides: HashMap::new(),
+            prune_horizon: Arc::new(AtomicU64::new(0)),
             column_families: Column::all().to_vec(),
         }
     }
+
+    /// Updates the period-pruning horizon. Entries below `period` in
+    /// uint64-keyed columns are dropped on the next compaction of those
+    /// families; passing zero disables pruning.
+    pub fn set_prune_horizon(&self, period: u64) {
+        self.prune_horizon.store(period, AtomicOrdering::Relaxed);
+    }
+
+    /// Loads a configuration from a node TOML file. The file is expected to hold
+    /// a `[storage]` table carrying at least `base_path`; every other key falls
+    /// back to the [`Config::new`] default when omitted.
+    pub fn from_toml(path: impl AsRef<Path>) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(StorageError::Io)?;
+        let root: TomlRoot = toml::from_str(&text)
+            .map_err(|e| StorageError::Config(format!("Invalid storage TOML: {}", e)))?;
+        let base_path = root.storage.base_path.clone().ok_or_else(|| {
+            StorageError::Config("storage.base_path is required".to_string())
+        })?;
+        Self::with_overrides(base_path, root.storage)
+    }
+
+    /// Applies a parsed `[storage]` section on top of the defaults for
+    /// `base_path`, translating the string compression names to
+    /// [`rocksdb::DBCompressionType`] and folding per-column tables into
+    /// [`Config::column_overrides`].
+    pub fn with_overrides(base_path: PathBuf, section: StorageSection) -> Result<Self> {
+        let mut config = Config::new(base_path);
+        if let Some(compression) = &section.compression {
+            config.compression = parse_compression(compression)?;
+        }
+        if let Some(v) = section.max_total_wal_size {
+            config.max_total_wal_size = v;
+        }
+        if let Some(v) = section.db_write_buffer_size {
+            config.db_write_buffer_size = v;
+        }
+        if let Some(v) = section.max_open_files {
+            config.max_open_files = v;
+        }
+        if let Some(v) = section.bulk_load {
+            config.bulk_load = v;
+        }
+        if let Some(v) = section.enable_metrics {
+            config.enable_metrics = v;
+        }
+        for (name, over) in &section.columns {
+            let col = Column::from_name(name)?;
+            let mut opts = col.options();
+            if let Some(compression) = &over.compression {
+                opts.compression = parse_compression(compression)?;
+            }
+            if let Some(v) = over.block_cache_size {
+                opts.block_cache_size = v;
+            }
+            if let Some(v) = over.bloom_bits_per_key {
+                opts.bloom_bits_per_key = v;
+            }
+            if let Some(v) = over.block_size {
+                opts.block_size = v;
+            }
+            if let Some(v) = over.write_buffer_size {
+                opts.write_buffer_size = v;
+            }
+            if let Some(v) = over.max_write_buffer_number {
+                opts.max_write_buffer_number = v;
+            }
+            if let Some(v) = over.prefix_extractor_len {
+                opts.prefix_extractor_len = Some(v);
+            }
+            config.column_overrides.insert(col, opts);
+        }
+        Ok(config)
+    }
+}
+
+/// Top-level wrapper matching the node TOML layout (`[storage]` table).
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct TomlRoot {
+    #[serde(default)]
+    storage: StorageSection,
+}
+
+/// Deserialized `[storage]` section. Every field is optional so operators only
+/// specify the knobs they want to move off the defaults.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct StorageSection {
+    pub base_path: Option<PathBuf>,
+    pub compression: Option<String>,
+    pub max_total_wal_size: Option<u64>,
+    pub db_write_buffer_size: Option<usize>,
+    pub max_open_files: Option<i32>,
+    pub bulk_load: Option<bool>,
+    pub enable_metrics: Option<bool>,
+    #[serde(default)]
+    pub columns: HashMap<String, ColumnOverride>,
+}
+
+/// Per-column overrides under `[storage.columns.<name>]`, where `<name>` is the
+/// [`Column::name`] string. Unset fields inherit [`Column::options`].
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct ColumnOverride {
+    pub compression: Option<String>,
+    pub block_cache_size: Option<usize>,
+    pub bloom_bits_per_key: Option<f64>,
+    pub block_size: Option<usize>,
+    pub write_buffer_size: Option<usize>,
+    pub max_write_buffer_number: Option<i32>,
+    pub prefix_extractor_len: Option<usize>,
+}
+
+/// Maps a human-friendly compression name to its RocksDB type.
+fn parse_compression(name: &str) -> Result<rocksdb::DBCompressionType> {
+    match name.to_ascii_lowercase().as_str() {
+        "none" => Ok(rocksdb::DBCompressionType::None),
+        "snappy" => Ok(rocksdb::DBCompressionType::Snappy),
+        "lz4" => Ok(rocksdb::DBCompressionType::Lz4),
+        "zlib" => Ok(rocksdb::DBCompressionType::Zlib),
+        "zstd" => Ok(rocksdb::DBCompressionType::Zstd),
+        other => Err(StorageError::Config(format!("Unknown compression type: {}", other)).into()),
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -80,6 +231,55 @@ pub enum Column {
 
 type ComparatorFn = Box<dyn Fn(&[u8], &[u8]) -> Ordering>;
 
+/// Per-column-family RocksDB tuning knobs.
+///
+/// Every column family used to receive the same global options; real workloads
+/// want them tailored. High-churn hot columns favour large block caches and
+/// bloom filters for point lookups, while cold append-only columns favour bigger
+/// blocks and stronger compression. This mirrors how Solana's blockstore assigns
+/// per-column-family options rather than one blanket config.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnOptions {
+    pub compression: rocksdb::DBCompressionType,
+    pub block_cache_size: usize,
+    pub bloom_bits_per_key: f64,
+    pub block_size: usize,
+    pub write_buffer_size: usize,
+    pub max_write_buffer_number: i32,
+    /// Length, in bytes, of the fixed key prefix to index with a prefix
+    /// extractor. When set, `seek`/`iter_prefix` scans restricted to that
+    /// prefix consult a prefix bloom filter and skip whole SST files; `None`
+    /// leaves prefix seeks as full ordered scans. Disabled by default.
+    pub prefix_extractor_len: Option<usize>,
+}
+
+impl Default for ColumnOptions {
+    fn default() -> Self {
+        ColumnOptions {
+            compression: rocksdb::DBCompressionType::Lz4,
+            block_cache_size: 8 * 1024 * 1024, // 8 MiB
+            bloom_bits_per_key: 0.0,           // disabled by default
+            block_size: 4 * 1024,             // 4 KiB
+            write_buffer_size: 64 * 1024 * 1024, // 64 MiB
+            max_write_buffer_number: 2,
+            prefix_extractor_len: None,
+        }
+    }
+}
+
+/// Per-column RocksDB BlobDB (key-value separation) settings.
+///
+/// Large-value families pay for every byte on every compaction because values
+/// travel with the LSM tree. Storing values above `min_blob_size` out-of-line in
+/// blob files leaves only small pointers in the tree, so compaction moves far
+/// less data. Index-only columns keep the normal path and return `None`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlobConfig {
+    pub min_blob_size: u64,
+    pub blob_file_size: u64,
+    pub enable_blob_garbage_collection: bool,
+}
+
 impl Column {
     /// Returns the column family name.
     pub fn name(&self) -> &'static str {
@@ -223,12 +423,129 @@ impl Column {
         )
     }
 
-    /// Creates a ColumnFamilyDescriptor for this column.
+    /// Returns the per-column tuning options for this family.
+    ///
+    /// Hot, high-churn columns (`Status`, `PbftMgrRoundStep`,
+    /// `LatestRoundOwnVotes`) get large block caches and bloom filters; cold,
+    /// append-only columns (`PeriodData`, `FinalChainReceiptByPeriod`) get bigger
+    /// blocks and stronger Zstd compression.
+    pub fn options(&self) -> ColumnOptions {
+        match self {
+            Column::Status
+            | Column::PbftMgrRoundStep
+            | Column::PbftMgrStatus
+            | Column::LatestRoundOwnVotes
+            | Column::LatestRoundTwoTPlusOneVotes => ColumnOptions {
+                compression: rocksdb::DBCompressionType::Lz4,
+                block_cache_size: 64 * 1024 * 1024, // 64 MiB
+                bloom_bits_per_key: 10.0,
+                block_size: 4 * 1024,
+                write_buffer_size: 64 * 1024 * 1024,
+                max_write_buffer_number: 4,
+                prefix_extractor_len: None,
+            },
+            Column::PeriodData
+            | Column::FinalChainReceiptByPeriod
+            | Column::PeriodSystemTransactions => ColumnOptions {
+                compression: rocksdb::DBCompressionType::Zstd,
+                block_cache_size: 8 * 1024 * 1024,
+                bloom_bits_per_key: 0.0,
+                block_size: 32 * 1024, // larger blocks for cold scans
+                write_buffer_size: 128 * 1024 * 1024,
+                max_write_buffer_number: 2,
+                prefix_extractor_len: None,
+            },
+            _ => ColumnOptions::default(),
+        }
+    }
+
+    /// Returns the BlobDB settings for this column, or `None` for families that
+    /// should stay on the normal (inline-value) path.
+    ///
+    /// Only the large-value families (`PeriodData`, `FinalChainReceiptByPeriod`,
+    /// `Transactions`, `DagBlocks`) opt in; index columns such as
+    /// `DagBlockPeriod` and `TrxPeriod` store tiny values and gain nothing.
+    pub fn blob_config(&self) -> Option<BlobConfig> {
+        match self {
+            Column::PeriodData
+            | Column::FinalChainReceiptByPeriod
+            | Column::Transactions
+            | Column::DagBlocks => Some(BlobConfig {
+                min_blob_size: 4 * 1024,           // 4 KiB
+                blob_file_size: 256 * 1024 * 1024, // 256 MiB
+                enable_blob_garbage_collection: true,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Creates a ColumnFamilyDescriptor for this column, applying its per-column
+    /// tuning on top of the shared base options.
     pub fn descriptor(&self, opts: &rocksdb::Options) -> rocksdb::ColumnFamilyDescriptor {
+        self.descriptor_with(opts, None, None)
+    }
+
+    /// Like [`Column::descriptor`], but prefers an operator-supplied override
+    /// (from the TOML `[storage.columns]` table) over the built-in options and
+    /// installs the period-horizon compaction filter on uint64-keyed columns
+    /// when a shared horizon is supplied.
+    pub fn descriptor_with(
+        &self,
+        opts: &rocksdb::Options,
+        override_opts: Option<&ColumnOptions>,
+        prune_horizon: Option<&Arc<AtomicU64>>,
+    ) -> rocksdb::ColumnFamilyDescriptor {
         let mut opts = opts.clone();
         if self.uses_uint64_comparator() {
             opts.set_comparator("taraxa.UintComparator", Self::uint64_comparator());
+            if let Some(horizon) = prune_horizon {
+                let horizon = horizon.clone();
+                opts.set_compaction_filter("taraxa.period_horizon", move |_level, key, _value| {
+                    // Keep everything when pruning is disabled, and never touch
+                    // keys that are not the expected little-endian u64 period.
+                    let below = horizon.load(AtomicOrdering::Relaxed);
+                    if below != 0 && key.len() == 8 {
+                        let period = u64::from_le_bytes(key.try_into().unwrap());
+                        if period < below {
+                            return rocksdb::compaction_filter::Decision::Remove;
+                        }
+                    }
+                    rocksdb::compaction_filter::Decision::Keep
+                });
+            }
         }
+
+        let col_opts = override_opts.copied().unwrap_or_else(|| self.options());
+        opts.set_compression_type(col_opts.compression);
+        opts.set_write_buffer_size(col_opts.write_buffer_size);
+        opts.set_max_write_buffer_number(col_opts.max_write_buffer_number);
+
+        let mut block_opts = rocksdb::BlockBasedOptions::default();
+        block_opts.set_block_size(col_opts.block_size);
+        if col_opts.block_cache_size > 0 {
+            let cache = rocksdb::Cache::new_lru_cache(col_opts.block_cache_size);
+            block_opts.set_block_cache(&cache);
+        }
+        if col_opts.bloom_bits_per_key > 0.0 {
+            block_opts.set_bloom_filter(col_opts.bloom_bits_per_key, false);
+        }
+        opts.set_block_based_table_factory(&block_opts);
+
+        if let Some(len) = col_opts.prefix_extractor_len {
+            // A fixed-length prefix extractor lets prefix seeks consult the
+            // prefix bloom filter; keep whole-key filtering so point lookups on
+            // the full key stay cheap too.
+            opts.set_prefix_extractor(rocksdb::SliceTransform::create_fixed_prefix(len));
+            opts.set_memtable_prefix_bloom_ratio(0.1);
+        }
+
+        if let Some(blob) = self.blob_config() {
+            opts.set_enable_blob_files(true);
+            opts.set_min_blob_size(blob.min_blob_size);
+            opts.set_blob_file_size(blob.blob_file_size);
+            opts.set_enable_blob_garbage_collection(blob.enable_blob_garbage_collection);
+        }
+
         rocksdb::ColumnFamilyDescriptor::new(self.name(), opts)
     }
 
@@ -272,6 +589,7 @@ mod tests {
         assert_eq!(config.max_total_wal_size, 1024 * 1024 * 1024);
         assert_eq!(config.db_write_buffer_size, 2 * 1024 * 1024 * 1024);
         assert_eq!(config.max_open_files, 256);
+        assert!(!config.bulk_load);
     }
 
     #[test]
@@ -338,6 +656,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hot_columns_have_bloom_and_large_cache() {
+        let opts = Column::Status.options();
+        assert!(opts.bloom_bits_per_key > 0.0);
+        assert!(opts.block_cache_size >= 64 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_cold_columns_use_zstd_and_big_blocks() {
+        let opts = Column::PeriodData.options();
+        assert_eq!(opts.compression, rocksdb::DBCompressionType::Zstd);
+        assert!(opts.block_size >= 32 * 1024);
+    }
+
+    #[test]
+    fn test_default_columns_use_default_options() {
+        assert_eq!(Column::Migrations.options(), ColumnOptions::default());
+    }
+
+    #[test]
+    fn test_prune_horizon_defaults_to_disabled() {
+        let config = Config::new(PathBuf::from("/tmp/node"));
+        assert_eq!(config.prune_horizon.load(AtomicOrdering::Relaxed), 0);
+        config.set_prune_horizon(42);
+        assert_eq!(config.prune_horizon.load(AtomicOrdering::Relaxed), 42);
+    }
+
+    #[test]
+    fn test_with_overrides_parses_storage_section() {
+        let text = r#"
+[storage]
+base_path = "/tmp/node"
+compression = "zstd"
+max_open_files = 1024
+bulk_load = true
+
+[storage.columns.status]
+block_cache_size = 134217728
+bloom_bits_per_key = 12.0
+"#;
+        let root: TomlRoot = toml::from_str(text).unwrap();
+        let config =
+            Config::with_overrides(PathBuf::from("/tmp/node"), root.storage).unwrap();
+
+        assert_eq!(config.compression, rocksdb::DBCompressionType::Zstd);
+        assert_eq!(config.max_open_files, 1024);
+        assert!(config.bulk_load);
+
+        let status = config.column_overrides.get(&Column::Status).unwrap();
+        assert_eq!(status.block_cache_size, 134217728);
+        assert_eq!(status.bloom_bits_per_key, 12.0);
+    }
+
+    #[test]
+    fn test_parse_compression_rejects_unknown() {
+        let text = "[storage]\ncompression = \"brotli\"\n";
+        let root: TomlRoot = toml::from_str(text).unwrap();
+        assert!(Config::with_overrides(PathBuf::from("/tmp/node"), root.storage).is_err());
+    }
+
+    #[test]
+    fn test_large_value_columns_enable_blob() {
+        assert!(Column::PeriodData.blob_config().is_some());
+        assert!(Column::Transactions.blob_config().is_some());
+        assert!(Column::DagBlocks.blob_config().is_some());
+        let blob = Column::PeriodData.blob_config().unwrap();
+        assert!(blob.enable_blob_garbage_collection);
+        assert!(blob.min_blob_size > 0);
+    }
+
+    #[test]
+    fn test_index_columns_skip_blob() {
+        assert!(Column::DagBlockPeriod.blob_config().is_none());
+        assert!(Column::TrxPeriod.blob_config().is_none());
+    }
+
     #[test]
     fn test_all_columns_have_names() {
         for column in Column::all() {
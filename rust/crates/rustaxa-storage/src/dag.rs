@@ -1,19 +1,45 @@
 use anyhow::Result;
 use ethereum_types::H256;
 use rustaxa_types::{DagBlock, TypesError};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 
+use crate::cht;
 use crate::db::DbReader;
+use crate::write::{DbWriter, WriteBatch};
 use crate::{Column, StorageError};
 
 pub struct DagRepository<D: DbReader> {
     db: Arc<D>,
+    /// When set, [`insert_dag_block`](DagRepository::insert_dag_block) verifies
+    /// each block's Wesolowski VDF proof against this shared RSA modulus and
+    /// rejects blocks whose proof-of-delay is missing or invalid.
+    vdf_modulus: Option<Vec<u8>>,
+}
+
+/// The current DAG frontier: the non-finalized blocks that nothing else
+/// references as a pivot or tip. `tips` is ordered by descending level, and
+/// `pivot` is the highest-level tip — the anchor a proposer usually builds on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DagFrontier {
+    pub tips: Vec<H256>,
+    pub pivot: Option<H256>,
 }
 
 impl<D: DbReader> DagRepository<D> {
     pub fn new(db: Arc<D>) -> Self {
-        DagRepository { db }
+        DagRepository {
+            db,
+            vdf_modulus: None,
+        }
+    }
+
+    /// Enables VDF proof validation on insert, checking each block against the
+    /// shared RSA `modulus`. Off by default so light nodes and simulations that
+    /// trust their input keep inserting without the verification cost.
+    pub fn with_vdf_modulus(mut self, modulus: Vec<u8>) -> Self {
+        self.vdf_modulus = Some(modulus);
+        self
     }
 
     /// Implements dagBlockInDb(blockHash) -> bool
@@ -38,6 +64,62 @@ impl<D: DbReader> DagRepository<D> {
         Ok(DagBlock::from_rlp_bytes(&bytes)?)
     }
 
+    /// Strictly decodes a stored block, rejecting malformed encodings up front
+    /// instead of trusting the bytes on disk. The RLP must be a list of exactly
+    /// eight items (pivot, level, timestamp, vdf, tips, transactions, signature,
+    /// gas_estimation) with a 65-byte signature. When `expected_level` is
+    /// supplied the decoded level must match it, and the hash must be listed in
+    /// the `DagBlocksLevel` index at that level.
+    pub fn dag_block_checked(&self, block: H256, expected_level: Option<u64>) -> Result<DagBlock> {
+        let bytes = self.dag_block_rlp(block)?;
+        let decoded = Self::decode_dag_block_strict(&bytes)?;
+        if let Some(level) = expected_level {
+            if decoded.level != level {
+                return Err(StorageError::Dag(format!(
+                    "DAG block level mismatch: encoded {}, expected {}",
+                    decoded.level, level
+                ))
+                .into());
+            }
+            if !self.blocks_by_level(level)?.contains(&block) {
+                return Err(StorageError::Dag(format!(
+                    "DAG block {:?} missing from level {} index",
+                    block, level
+                ))
+                .into());
+            }
+        }
+        Ok(decoded)
+    }
+
+    /// Decodes a DAG block, asserting the structural invariants a trusted
+    /// encoding must satisfy before returning it.
+    fn decode_dag_block_strict(bytes: &[u8]) -> Result<DagBlock> {
+        let rlp = rlp::Rlp::new(bytes);
+        if !rlp.is_list() {
+            return Err(StorageError::Dag("DAG block RLP is not a list".to_string()).into());
+        }
+        let count = rlp.item_count().map_err(TypesError::from)?;
+        if count != 8 {
+            return Err(StorageError::Dag(format!(
+                "DAG block RLP has {} items, expected 8",
+                count
+            ))
+            .into());
+        }
+        // Signature is item 6; enforce its 65-byte width from the raw encoding.
+        let sig = rlp.at(6).map_err(TypesError::from)?;
+        let sig_bytes = sig.data().map_err(TypesError::from)?;
+        if sig_bytes.len() != 65 {
+            return Err(StorageError::Dag(format!(
+                "DAG block signature is {} bytes, expected 65",
+                sig_bytes.len()
+            ))
+            .into());
+        }
+        Ok(DagBlock::from_rlp_bytes(bytes)?)
+    }
+
     /// Implements GetDagBlockPeriod() -> (uint64, uint32) (finalized)
     pub fn dag_block_period(&self, block: H256) -> Result<(u64, u32)> {
         let value = self
@@ -101,6 +183,79 @@ impl<D: DbReader> DagRepository<D> {
         Ok(map)
     }
 
+    /// Computes the DAG frontier from the non-finalized block set.
+    ///
+    /// Every non-finalized block is decoded from `Column::DagBlocks`; its pivot
+    /// and tips are recorded as "referenced". A block whose hash is never
+    /// referenced is a leaf of the current DAG and forms the frontier. The
+    /// returned tips are ordered by descending level, and the highest-level tip
+    /// is surfaced separately as the suggested pivot.
+    pub fn dag_tips(&self) -> Result<DagFrontier> {
+        let mut levels: Vec<(H256, u64)> = Vec::new();
+        let mut referenced: HashSet<H256> = HashSet::new();
+
+        for res in self.db.iter(Column::DagBlocks) {
+            let (key, value) = res?;
+            let hash = H256::from_slice(&key);
+            let block = DagBlock::from_rlp_bytes(&value)?;
+            referenced.insert(block.pivot);
+            referenced.extend(block.tips.iter().copied());
+            levels.push((hash, block.level));
+        }
+
+        let mut tips: Vec<(H256, u64)> = levels
+            .into_iter()
+            .filter(|(hash, _)| !referenced.contains(hash))
+            .collect();
+        // Highest level first; break ties on the hash for determinism.
+        tips.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let pivot = tips.first().map(|(hash, _)| *hash);
+        Ok(DagFrontier {
+            tips: tips.into_iter().map(|(hash, _)| hash).collect(),
+            pivot,
+        })
+    }
+
+    /// Lazily yields each non-finalized block paired with its level, decoding
+    /// straight from the `Column::DagBlocks` iterator without materializing the
+    /// whole set into a map first. Preferred over [`nonfinalized_dag_blocks`]
+    /// when the non-finalized set is large during sync.
+    pub fn iter_nonfinalized_by_level(
+        &self,
+    ) -> impl Iterator<Item = Result<(u64, DagBlock)>> + '_ {
+        self.db.iter(Column::DagBlocks).map(|res| {
+            let (_, value) = res?;
+            let block = DagBlock::from_rlp_bytes(&value)?;
+            Ok((block.level, block))
+        })
+    }
+
+    /// Like [`iter_nonfinalized_by_level`], but only yields blocks whose level
+    /// falls within `[from, to]` (inclusive).
+    pub fn nonfinalized_levels_range(
+        &self,
+        from: u64,
+        to: u64,
+    ) -> impl Iterator<Item = Result<(u64, DagBlock)>> + '_ {
+        self.iter_nonfinalized_by_level().filter_map(move |res| match res {
+            Ok((level, block)) if (from..=to).contains(&level) => Some(Ok((level, block))),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+    }
+
+    /// Counts non-finalized blocks by walking the column entries without any
+    /// RLP decoding.
+    pub fn count_nonfinalized(&self) -> Result<u64> {
+        let mut count = 0u64;
+        for res in self.db.iter(Column::DagBlocks) {
+            res?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
     /// Implements GetProposalPeriodForDagLevel(level) -> uint64
     pub fn proposal_period_for_dag_level(&self, level: u64) -> Result<Option<u64>> {
         match self
@@ -160,6 +315,102 @@ impl<D: DbReader> DagRepository<D> {
         Ok(res)
     }
 
+    /// Strict counterpart to [`dag_blocks_at_level_rlp`]: instead of silently
+    /// skipping blocks that fail to decode, it validates each block's structure
+    /// and surfaces the first corruption as a `StorageError::Dag`.
+    pub fn dag_blocks_at_level_rlp_checked(
+        &self,
+        level: u64,
+        number_of_levels: u32,
+    ) -> Result<Vec<Vec<u8>>> {
+        let mut res = Vec::new();
+        for i in 0..number_of_levels {
+            let l = level + i as u64;
+            for hash in self.blocks_by_level(l)? {
+                let rlp = self.dag_block_rlp(hash)?;
+                Self::decode_dag_block_strict(&rlp)?;
+                res.push(rlp);
+            }
+        }
+        Ok(res)
+    }
+
+    /// Collects the level hash-sets for `[start, start + count)` as Merkle
+    /// leaves, one per level in order.
+    fn level_leaves(&self, start: u64, count: u64) -> Result<Vec<H256>> {
+        (start..start + count)
+            .map(|level| Ok(cht::level_leaf(&self.blocks_by_level(level)?)))
+            .collect()
+    }
+
+    /// Returns the canonical level-hash-trie root over `[start, start + count)`,
+    /// a compact commitment a light client can pin and check `blocks_by_level`
+    /// results against.
+    pub fn level_set_root(&self, start: u64, count: u64) -> Result<H256> {
+        Ok(cht::merkle_root(&self.level_leaves(start, count)?))
+    }
+
+    /// Builds a proof that `level` belongs to the trie over `[start, start + count)`:
+    /// the level's hash set together with the Merkle branch linking it to the
+    /// root. Verify with [`cht::verify_level_set_proof`].
+    pub fn level_set_proof(
+        &self,
+        start: u64,
+        count: u64,
+        level: u64,
+    ) -> Result<(Vec<H256>, Vec<H256>)> {
+        if level < start || level >= start + count {
+            return Err(StorageError::Dag(format!(
+                "level {} outside proof range [{}, {})",
+                level,
+                start,
+                start + count
+            ))
+            .into());
+        }
+        let leaves = self.level_leaves(start, count)?;
+        let hashes = self.blocks_by_level(level)?;
+        let branch = cht::merkle_branch(&leaves, (level - start) as usize);
+        Ok((hashes, branch))
+    }
+
+    /// Iterates the finalized block -> period index, yielding each block's key
+    /// bytes and its proposal period. Used by pruning to find blocks below a
+    /// retention boundary.
+    pub fn iter_block_periods(&self) -> impl Iterator<Item = Result<(Vec<u8>, u64)>> + '_ {
+        self.db.iter(Column::DagBlockPeriod).map(|res| {
+            let (key, value) = res?;
+            let rlp = rlp::Rlp::new(value.as_ref());
+            let period: u64 = rlp.val_at(0)?;
+            Ok((key.to_vec(), period))
+        })
+    }
+
+    /// Iterates the level -> proposal-period index, yielding each level's key
+    /// bytes and the period it was finalized into.
+    pub fn iter_proposal_periods(&self) -> impl Iterator<Item = Result<(Vec<u8>, u64)>> + '_ {
+        self.db.iter(Column::ProposalPeriodLevelsMap).map(|res| {
+            let (key, value) = res?;
+            if value.as_ref().len() != 8 {
+                return Err(StorageError::Dag("Invalid period data size".to_string()).into());
+            }
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(value.as_ref());
+            Ok((key.to_vec(), u64::from_le_bytes(bytes)))
+        })
+    }
+
+    /// Returns the highest proposal period recorded in the level -> period
+    /// index, or 0 if the index is empty.
+    pub fn last_proposal_period(&self) -> Result<u64> {
+        let mut max = 0u64;
+        for res in self.iter_proposal_periods() {
+            let (_, period) = res?;
+            max = max.max(period);
+        }
+        Ok(max)
+    }
+
     pub fn nonfinalized_dag_blocks_rlp(&self) -> Result<Vec<(u64, Vec<Vec<u8>>)>> {
         let mut map: BTreeMap<u64, Vec<Vec<u8>>> = BTreeMap::new();
         for res in self.db.iter(Column::DagBlocks) {
@@ -173,6 +424,44 @@ impl<D: DbReader> DagRepository<D> {
     }
 }
 
+impl<D: DbReader + DbWriter> DagRepository<D> {
+    /// Persists a non-finalized `block` under `hash`, keeping the block bytes in
+    /// `DagBlocks` and the `DagBlocksLevel` index for the block's level in sync.
+    ///
+    /// The block encoding and the updated level index are committed in a single
+    /// [`WriteBatch`], so a reader never observes a block that is missing from
+    /// its level set or a level entry pointing at an absent block.
+    pub fn insert_dag_block(&self, hash: H256, block: &DagBlock) -> Result<()> {
+        if let Some(modulus) = &self.vdf_modulus {
+            if !block.verify_vdf(modulus) {
+                return Err(StorageError::Dag(format!(
+                    "DAG block {:?} has an invalid or missing VDF proof",
+                    hash
+                ))
+                .into());
+            }
+        }
+
+        let mut level_hashes = self.blocks_by_level(block.level)?;
+        if !level_hashes.contains(&hash) {
+            level_hashes.push(hash);
+        }
+        let mut level_rlp = rlp::RlpStream::new_list(level_hashes.len());
+        for h in &level_hashes {
+            level_rlp.append(h);
+        }
+
+        let mut batch = WriteBatch::new();
+        batch.put(Column::DagBlocks, hash.as_bytes(), &block.to_rlp_bytes());
+        batch.put(
+            Column::DagBlocksLevel,
+            &block.level.to_le_bytes(),
+            &level_rlp.out(),
+        );
+        self.db.write_batch(batch)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -426,6 +715,85 @@ mod tests {
         assert_eq!(result, Some(period));
     }
 
+    #[test]
+    fn test_iter_proposal_periods_and_last() {
+        let db = Arc::new(MockDagStore::new());
+        let repo = DagRepository::new(db.clone());
+
+        // levels 1,2,3 finalized into periods 5,5,9
+        for (level, period) in [(1u64, 5u64), (2, 5), (3, 9)] {
+            db.put(
+                Column::ProposalPeriodLevelsMap,
+                &level.to_le_bytes(),
+                &period.to_le_bytes(),
+            );
+        }
+
+        let periods: Vec<_> = repo
+            .iter_proposal_periods()
+            .map(|r| r.unwrap().1)
+            .collect();
+        assert_eq!(periods, vec![5, 5, 9]);
+        assert_eq!(repo.last_proposal_period().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_iter_block_periods() {
+        let db = Arc::new(MockDagStore::new());
+        let repo = DagRepository::new(db.clone());
+
+        let hash = H256::random();
+        let mut stream = RlpStream::new_list(2);
+        stream.append(&7u64);
+        stream.append(&0u32);
+        db.put(Column::DagBlockPeriod, hash.as_bytes(), &stream.out());
+
+        let entries: Vec<_> = repo.iter_block_periods().map(|r| r.unwrap()).collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].1, 7);
+    }
+
+    fn dag_block_rlp_with(level: u64, pivot: H256, tips: &[H256]) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(8);
+        stream.append(&pivot);
+        stream.append(&level);
+        stream.append(&123456789u64);
+        stream.append(&vec![1u8, 2, 3]);
+        stream.begin_list(tips.len());
+        for t in tips {
+            stream.append(t);
+        }
+        stream.begin_list(0);
+        stream.append(&vec![0u8; 65]);
+        stream.append(&1000u64);
+        stream.out().to_vec()
+    }
+
+    #[test]
+    fn test_dag_tips() {
+        let db = Arc::new(MockDagStore::new());
+        let repo = DagRepository::new(db.clone());
+
+        // root (level 1) <- child (level 2); child references root as pivot.
+        let root = H256::random();
+        let child = H256::random();
+        db.put(
+            Column::DagBlocks,
+            root.as_bytes(),
+            &dag_block_rlp_with(1, H256::zero(), &[]),
+        );
+        db.put(
+            Column::DagBlocks,
+            child.as_bytes(),
+            &dag_block_rlp_with(2, root, &[]),
+        );
+
+        let frontier = repo.dag_tips().unwrap();
+        // Only the child is a leaf; root is referenced as a pivot.
+        assert_eq!(frontier.tips, vec![child]);
+        assert_eq!(frontier.pivot, Some(child));
+    }
+
     #[test]
     fn test_nonfinalized_dag_blocks() {
         let db = Arc::new(MockDagStore::new());
@@ -449,4 +817,138 @@ mod tests {
         assert_eq!(result.len(), 1); // 1 level
         assert_eq!(result.get(&10).unwrap().len(), 2);
     }
+
+    #[test]
+    fn test_level_set_proof_round_trip() {
+        let db = Arc::new(MockDagStore::new());
+        let repo = DagRepository::new(db.clone());
+
+        let start = 100u64;
+        let count = 4u64;
+        for level in start..start + count {
+            let hashes = vec![H256::random(), H256::random()];
+            let mut stream = RlpStream::new_list(hashes.len());
+            for h in &hashes {
+                stream.append(h);
+            }
+            db.put(Column::DagBlocksLevel, &level.to_le_bytes(), &stream.out());
+        }
+
+        let root = repo.level_set_root(start, count).unwrap();
+        let target = start + 2;
+        let (hashes, branch) = repo.level_set_proof(start, count, target).unwrap();
+        assert!(cht::verify_level_set_proof(
+            root, start, target, &hashes, &branch
+        ));
+
+        // A padded result must not verify against the trusted root.
+        let mut padded = hashes.clone();
+        padded.push(H256::random());
+        assert!(!cht::verify_level_set_proof(
+            root, start, target, &padded, &branch
+        ));
+    }
+
+    #[test]
+    fn test_dag_block_checked() {
+        let db = Arc::new(MockDagStore::new());
+        let repo = DagRepository::new(db.clone());
+
+        let hash = H256::random();
+        db.put(
+            Column::DagBlocks,
+            hash.as_bytes(),
+            &dag_block_rlp_with(10, H256::zero(), &[]),
+        );
+        let mut stream = RlpStream::new_list(1);
+        stream.append(&hash);
+        db.put(Column::DagBlocksLevel, &10u64.to_le_bytes(), &stream.out());
+
+        // Correct level passes; wrong level is rejected.
+        assert_eq!(repo.dag_block_checked(hash, Some(10)).unwrap().level, 10);
+        assert!(repo.dag_block_checked(hash, Some(11)).is_err());
+    }
+
+    #[test]
+    fn test_dag_block_checked_rejects_malformed() {
+        let db = Arc::new(MockDagStore::new());
+        let repo = DagRepository::new(db.clone());
+
+        let hash = H256::random();
+        // A 3-item list is not a valid DAG block encoding.
+        let mut stream = RlpStream::new_list(3);
+        stream.append(&H256::zero());
+        stream.append(&1u64);
+        stream.append(&2u64);
+        db.put(Column::DagBlocks, hash.as_bytes(), &stream.out());
+
+        let err = repo.dag_block_checked(hash, None).unwrap_err();
+        assert!(err.to_string().contains("expected 8"));
+    }
+
+    #[test]
+    fn test_insert_dag_block_round_trip() {
+        use crate::MemoryDb;
+
+        let db = Arc::new(MemoryDb::new());
+        let repo = DagRepository::new(db.clone());
+
+        let hash = H256::random();
+        let block = DagBlock::from_rlp_bytes(&dag_block_rlp_with(10, H256::zero(), &[])).unwrap();
+
+        repo.insert_dag_block(hash, &block).unwrap();
+
+        // The block is readable and its level index lists it.
+        assert_eq!(repo.dag_block(hash).unwrap(), block);
+        assert_eq!(repo.blocks_by_level(10).unwrap(), vec![hash]);
+
+        // Re-inserting the same block does not duplicate the level entry.
+        repo.insert_dag_block(hash, &block).unwrap();
+        assert_eq!(repo.blocks_by_level(10).unwrap(), vec![hash]);
+    }
+
+    #[test]
+    fn test_streaming_nonfinalized() {
+        let db = Arc::new(MockDagStore::new());
+        let repo = DagRepository::new(db.clone());
+
+        db.put(
+            Column::DagBlocks,
+            H256::random().as_bytes(),
+            &dag_block_rlp_with(7, H256::zero(), &[]),
+        );
+        db.put(
+            Column::DagBlocks,
+            H256::random().as_bytes(),
+            &dag_block_rlp_with(12, H256::zero(), &[]),
+        );
+
+        assert_eq!(repo.count_nonfinalized().unwrap(), 2);
+
+        let levels: Vec<u64> = repo
+            .iter_nonfinalized_by_level()
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(levels.len(), 2);
+        assert!(levels.contains(&7) && levels.contains(&12));
+
+        let in_range: Vec<u64> = repo
+            .nonfinalized_levels_range(10, 20)
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(in_range, vec![12]);
+    }
+
+    #[test]
+    fn test_insert_rejects_invalid_vdf() {
+        let db = Arc::new(crate::MemoryDb::new());
+        let repo = DagRepository::new(db.clone()).with_vdf_modulus(vec![11u8, 13]);
+
+        // The test block carries a placeholder `vdf` payload that is not a valid
+        // proof, so validation must reject it and leave the store untouched.
+        let hash = H256::random();
+        let block = DagBlock::from_rlp_bytes(&dag_block_rlp_with(10, H256::zero(), &[])).unwrap();
+        assert!(repo.insert_dag_block(hash, &block).is_err());
+        assert!(!repo.dag_block_in_db(hash).unwrap());
+    }
 }
@@ -10,13 +10,17 @@
 ///
 use anyhow::Result;
 use ethereum_types::H256;
-use rocksdb::{DBPinnableSlice, DBWithThreadMode, MultiThreaded, Options};
+use rocksdb::{DBPinnableSlice, DBWithThreadMode, MultiThreaded, Options, WriteBatch};
 use std::sync::Arc;
 
+use crate::typed::GENESIS_COLUMN;
 use crate::Column;
 use crate::Config;
 use crate::DagRepository;
+use crate::DbWriter;
 use crate::StorageError;
+use crate::StorageMetrics;
+use crate::WriteBatch as StorageWriteBatch;
 
 /// Item returned by the database iterator.
 /// Key and Value are boxed slices.
@@ -36,6 +40,46 @@ pub trait DbReader: Send + Sync {
     fn get<'a>(&'a self, col: Column, key: &[u8]) -> Result<Option<Self::Slice<'a>>>;
     fn iter<'a>(&'a self, col: Column) -> DbIterator<'a>;
     fn iter_rev<'a>(&'a self, col: Column) -> DbIterator<'a>;
+
+    /// Forward iteration starting at the first key `>= key`.
+    ///
+    /// The default walks the whole column and skips smaller keys; RocksDB
+    /// overrides it with `IteratorMode::From` so the engine seeks straight to
+    /// the target and reads no earlier blocks.
+    fn seek<'a>(&'a self, col: Column, key: &[u8]) -> DbIterator<'a> {
+        let key = key.to_vec();
+        Box::new(self.iter(col).filter(move |res| match res {
+            Ok((k, _)) => k.as_ref() >= key.as_slice(),
+            Err(_) => true,
+        }))
+    }
+
+    /// Reverse iteration starting at the last key `<= key`.
+    fn seek_rev<'a>(&'a self, col: Column, key: &[u8]) -> DbIterator<'a> {
+        let key = key.to_vec();
+        Box::new(self.iter_rev(col).filter(move |res| match res {
+            Ok((k, _)) => k.as_ref() <= key.as_slice(),
+            Err(_) => true,
+        }))
+    }
+
+    /// Forward iteration over every key that begins with `prefix`.
+    fn iter_prefix<'a>(&'a self, col: Column, prefix: &[u8]) -> DbIterator<'a> {
+        let prefix = prefix.to_vec();
+        Box::new(self.seek(col, &prefix).take_while(move |res| match res {
+            Ok((k, _)) => k.starts_with(&prefix),
+            Err(_) => true,
+        }))
+    }
+
+    /// Forward iteration over the half-open key range `[start, end)`.
+    fn iter_range<'a>(&'a self, col: Column, start: &[u8], end: &[u8]) -> DbIterator<'a> {
+        let end = end.to_vec();
+        Box::new(self.seek(col, start).take_while(move |res| match res {
+            Ok((k, _)) => k.as_ref() < end.as_slice(),
+            Err(_) => true,
+        }))
+    }
 }
 
 impl DbReader for DBWithThreadMode<MultiThreaded> {
@@ -80,12 +124,50 @@ impl DbReader for DBWithThreadMode<MultiThreaded> {
             .into()))),
         }
     }
+
+    fn seek<'a>(&'a self, col: Column, key: &[u8]) -> DbIterator<'a> {
+        self.iter_from(col, key, rocksdb::Direction::Forward)
+    }
+
+    fn seek_rev<'a>(&'a self, col: Column, key: &[u8]) -> DbIterator<'a> {
+        self.iter_from(col, key, rocksdb::Direction::Reverse)
+    }
+}
+
+impl DBWithThreadMode<MultiThreaded> {
+    /// Seeks to `key` and iterates in `direction`, surfacing a missing column
+    /// family or a mid-iteration RocksDB status as a terminal `Err` item rather
+    /// than silently ending the scan.
+    fn iter_from<'a>(
+        &'a self,
+        col: Column,
+        key: &[u8],
+        direction: rocksdb::Direction,
+    ) -> DbIterator<'a> {
+        match self.cf_handle(col.name()) {
+            Some(handle) => {
+                let iter = self
+                    .iterator_cf(&handle, rocksdb::IteratorMode::From(key, direction))
+                    .map(|res| res.map_err(|e| StorageError::Database(e).into()));
+                Box::new(iter)
+            }
+            None => Box::new(std::iter::once(Err(StorageError::Config(format!(
+                "Missing column family: {}",
+                col.name()
+            ))
+            .into()))),
+        }
+    }
 }
 
 pub struct Storage {
     #[allow(dead_code)]
     db: Arc<DBWithThreadMode<MultiThreaded>>,
     dag: DagRepository<DBWithThreadMode<MultiThreaded>>,
+    columns: Vec<Column>,
+    /// Present only when `Config::enable_metrics` was set, so the read path
+    /// stays zero-overhead when metrics are off.
+    metrics: Option<Arc<StorageMetrics>>,
 }
 
 impl Storage {
@@ -100,10 +182,26 @@ impl Storage {
         opts.set_write_buffer_size(config.db_write_buffer_size);
         opts.set_max_open_files(config.max_open_files);
 
+        if config.bulk_load {
+            // Tune for a one-shot ingestion: no auto-compaction and level-0
+            // triggers pushed out of the way so writes never stall.
+            opts.prepare_for_bulk_load();
+            opts.set_level_zero_file_num_compaction_trigger(1 << 30);
+            opts.set_level_zero_slowdown_writes_trigger(1 << 30);
+            opts.set_level_zero_stop_writes_trigger(1 << 30);
+            opts.set_write_buffer_size(config.db_write_buffer_size.max(512 * 1024 * 1024));
+        }
+
         let descriptors = config
             .column_families
             .iter()
-            .map(|col| col.descriptor(&opts))
+            .map(|col| {
+                col.descriptor_with(
+                    &opts,
+                    config.column_overrides.get(col),
+                    Some(&config.prune_horizon),
+                )
+            })
             .collect::<Vec<_>>();
 
         let db = DBWithThreadMode::<MultiThreaded>::open_cf_descriptors(
@@ -116,7 +214,39 @@ impl Storage {
         let db = Arc::new(db);
         let dag = DagRepository::new(db.clone());
 
-        Ok(Storage { db, dag })
+        let metrics = if config.enable_metrics {
+            Some(Arc::new(StorageMetrics::new()))
+        } else {
+            None
+        };
+
+        Ok(Storage {
+            db,
+            dag,
+            columns: config.column_families,
+            metrics,
+        })
+    }
+
+    /// Returns the Prometheus registry backing the per-column storage metrics,
+    /// or `None` when metrics collection is disabled in the [`Config`].
+    pub fn metrics_registry(&self) -> Option<&prometheus::Registry> {
+        self.metrics.as_ref().map(|m| m.registry())
+    }
+
+    /// Completes a bulk-load session by compacting every column family from end
+    /// to end, folding the unsorted level-0 files produced during ingestion into
+    /// the regular LSM shape. Call this once after the one-shot import finishes,
+    /// then reopen the database with `bulk_load` cleared for steady-state use.
+    pub fn finish_bulk_load(&self) -> Result<()> {
+        for col in &self.columns {
+            let handle = self.db.cf_handle(col.name()).ok_or_else(|| {
+                StorageError::Config(format!("Missing column family: {}", col.name()))
+            })?;
+            self.db
+                .compact_range_cf(&handle, None::<&[u8]>, None::<&[u8]>);
+        }
+        Ok(())
     }
 
     pub fn dag(&self) -> &DagRepository<DBWithThreadMode<MultiThreaded>> {
@@ -124,24 +254,235 @@ impl Storage {
     }
 
     pub fn genesis_hash(&self) -> Result<Option<H256>> {
-        Ok(self
-            .get(Column::Genesis, &0i32.to_le_bytes())?
-            .map(|val| H256::from_slice(val.as_ref())))
+        GENESIS_COLUMN.get(self, &0)
     }
+
+    /// Deletes finalized DAG blocks (and their level/period index entries)
+    /// whose proposal period is strictly below `period`, in a single batched
+    /// write for atomicity. Non-finalized blocks (those only present in
+    /// `DagBlocks`) are left untouched.
+    ///
+    /// Returns the number of finalized blocks removed.
+    pub fn prune_dag_blocks_before(&self, period: u64) -> Result<u64> {
+        let mut batch = WriteBatch::default();
+        let mut removed: u64 = 0;
+
+        // Remove finalized block -> period index entries below the boundary.
+        for res in self.dag.iter_block_periods() {
+            let (hash_key, entry_period) = res?;
+            if entry_period < period {
+                batch_delete(&self.db, &mut batch, Column::DagBlockPeriod, &hash_key)?;
+                removed += 1;
+            }
+        }
+
+        // Remove level -> period and level -> hashes index entries for every
+        // level that finalized below the boundary.
+        for res in self.dag.iter_proposal_periods() {
+            let (level_key, level_period) = res?;
+            if level_period < period {
+                batch_delete(
+                    &self.db,
+                    &mut batch,
+                    Column::ProposalPeriodLevelsMap,
+                    &level_key,
+                )?;
+                batch_delete(&self.db, &mut batch, Column::DagBlocksLevel, &level_key)?;
+            }
+        }
+
+        self.db.write(batch).map_err(StorageError::Database)?;
+        Ok(removed)
+    }
+
+    /// Prunes every finalized DAG block older than the most recent `n` periods.
+    /// Returns the number of blocks removed.
+    pub fn prune_to_last_n_periods(&self, n: u64) -> Result<u64> {
+        let last_period = self.dag.last_proposal_period()?;
+        // Keep the periods `last_period - n + 1 ..= last_period`.
+        let boundary = (last_period + 1).saturating_sub(n);
+        if boundary == 0 {
+            return Ok(0);
+        }
+        self.prune_dag_blocks_before(boundary)
+    }
+
+    /// Returns the on-disk footprint of the DAG blocks column family, so callers
+    /// can decide when to trigger pruning.
+    pub fn dag_blocks_db_size(&self) -> Result<u64> {
+        let handle = self.db.cf_handle(Column::DagBlocks.name()).ok_or_else(|| {
+            StorageError::Config(format!("Missing column family: {}", Column::DagBlocks.name()))
+        })?;
+        let size = self
+            .db
+            .property_int_value_cf(&handle, "rocksdb.total-sst-files-size")
+            .map_err(StorageError::Database)?
+            .unwrap_or(0);
+        Ok(size)
+    }
+
+    /// Returns a consistent, point-in-time read view over the database.
+    ///
+    /// The returned [`SnapshotReader`] pins a RocksDB snapshot, so every `get`
+    /// and `iter` it serves observes the same frozen state even while writes
+    /// proceed on this `Storage`. DAG traversals that span many columns and
+    /// levels — e.g. walking the pivot chain across levels — get a stable view
+    /// instead of racing ongoing ingestion, and never observe a partially
+    /// applied [`WriteBatch`](crate::WriteBatch).
+    pub fn snapshot(&self) -> SnapshotReader<'_> {
+        SnapshotReader {
+            db: self.db.as_ref(),
+            snapshot: self.db.snapshot(),
+        }
+    }
+}
+
+/// A point-in-time read view backed by a pinned RocksDB snapshot, created by
+/// [`Storage::snapshot`]. It borrows the underlying database for column-family
+/// handles while the snapshot holds the frozen sequence number, so reads stay
+/// consistent for the lifetime of the reader.
+pub struct SnapshotReader<'a> {
+    db: &'a DBWithThreadMode<MultiThreaded>,
+    snapshot: rocksdb::SnapshotWithThreadMode<'a, DBWithThreadMode<MultiThreaded>>,
+}
+
+impl DbReader for SnapshotReader<'_> {
+    type Slice<'b>
+        = Vec<u8>
+    where
+        Self: 'b;
+
+    fn get<'b>(&'b self, col: Column, key: &[u8]) -> Result<Option<Self::Slice<'b>>> {
+        let handle = self.db.cf_handle(col.name()).ok_or_else(|| {
+            StorageError::Config(format!("Missing column family: {}", col.name()))
+        })?;
+        self.snapshot
+            .get_cf(&handle, key)
+            .map_err(|e| StorageError::Database(e).into())
+    }
+
+    fn iter<'b>(&'b self, col: Column) -> DbIterator<'b> {
+        self.snapshot_iter(col, rocksdb::IteratorMode::Start)
+    }
+
+    fn iter_rev<'b>(&'b self, col: Column) -> DbIterator<'b> {
+        self.snapshot_iter(col, rocksdb::IteratorMode::End)
+    }
+
+    fn seek<'b>(&'b self, col: Column, key: &[u8]) -> DbIterator<'b> {
+        self.snapshot_iter(col, rocksdb::IteratorMode::From(key, rocksdb::Direction::Forward))
+    }
+
+    fn seek_rev<'b>(&'b self, col: Column, key: &[u8]) -> DbIterator<'b> {
+        self.snapshot_iter(col, rocksdb::IteratorMode::From(key, rocksdb::Direction::Reverse))
+    }
+}
+
+impl<'a> SnapshotReader<'a> {
+    /// Iterates the snapshot over `col`, surfacing a missing column family or a
+    /// mid-iteration RocksDB status as a terminal `Err` item.
+    fn snapshot_iter<'b>(&'b self, col: Column, mode: rocksdb::IteratorMode) -> DbIterator<'b> {
+        match self.db.cf_handle(col.name()) {
+            Some(handle) => {
+                let iter = self
+                    .snapshot
+                    .iterator_cf(&handle, mode)
+                    .map(|res| res.map_err(|e| StorageError::Database(e).into()));
+                Box::new(iter)
+            }
+            None => Box::new(std::iter::once(Err(StorageError::Config(format!(
+                "Missing column family: {}",
+                col.name()
+            ))
+            .into()))),
+        }
+    }
+}
+
+/// Adds a delete of `key` in `col` to `batch`, resolving the column family
+/// handle.
+fn batch_delete(
+    db: &DBWithThreadMode<MultiThreaded>,
+    batch: &mut WriteBatch,
+    col: Column,
+    key: &[u8],
+) -> Result<()> {
+    let handle = db
+        .cf_handle(col.name())
+        .ok_or_else(|| StorageError::Config(format!("Missing column family: {}", col.name())))?;
+    batch.delete_cf(&handle, key);
+    Ok(())
 }
 
 impl DbReader for Storage {
     type Slice<'a> = DBPinnableSlice<'a>;
 
     fn get<'a>(&'a self, col: Column, key: &[u8]) -> Result<Option<Self::Slice<'a>>> {
-        DbReader::get(&*self.db, col, key)
+        // Fast path when metrics are off: a straight delegation, no timing.
+        let Some(metrics) = &self.metrics else {
+            return DbReader::get(&*self.db, col, key);
+        };
+        let _timer = metrics.start_get(col.name()).start_timer();
+        let result = DbReader::get(&*self.db, col, key);
+        if let Ok(value) = &result {
+            metrics.record_get(col.name(), key.len(), value.as_ref().map(|v| v.as_ref().len()));
+        }
+        result
     }
 
     fn iter<'a>(&'a self, col: Column) -> DbIterator<'a> {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_iter(col.name(), "iter");
+        }
         DbReader::iter(&*self.db, col)
     }
 
     fn iter_rev<'a>(&'a self, col: Column) -> DbIterator<'a> {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_iter(col.name(), "iter_rev");
+        }
         DbReader::iter_rev(&*self.db, col)
     }
+
+    fn seek<'a>(&'a self, col: Column, key: &[u8]) -> DbIterator<'a> {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_iter(col.name(), "seek");
+        }
+        DbReader::seek(&*self.db, col, key)
+    }
+
+    fn seek_rev<'a>(&'a self, col: Column, key: &[u8]) -> DbIterator<'a> {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_iter(col.name(), "seek_rev");
+        }
+        DbReader::seek_rev(&*self.db, col, key)
+    }
+
+    fn iter_prefix<'a>(&'a self, col: Column, prefix: &[u8]) -> DbIterator<'a> {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_iter(col.name(), "iter_prefix");
+        }
+        DbReader::iter_prefix(&*self.db, col, prefix)
+    }
+
+    fn iter_range<'a>(&'a self, col: Column, start: &[u8], end: &[u8]) -> DbIterator<'a> {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_iter(col.name(), "iter_range");
+        }
+        DbReader::iter_range(&*self.db, col, start, end)
+    }
+}
+
+impl DbWriter for Storage {
+    fn put(&self, col: Column, key: &[u8], value: &[u8]) -> Result<()> {
+        DbWriter::put(&*self.db, col, key, value)
+    }
+
+    fn delete(&self, col: Column, key: &[u8]) -> Result<()> {
+        DbWriter::delete(&*self.db, col, key)
+    }
+
+    fn write_batch(&self, batch: StorageWriteBatch) -> Result<()> {
+        DbWriter::write_batch(&*self.db, batch)
+    }
 }
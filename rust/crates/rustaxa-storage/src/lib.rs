@@ -1,11 +1,29 @@
+mod cache;
+pub mod cht;
 mod config;
 mod dag;
 mod db;
 mod error;
+mod memory;
+mod metrics;
+mod typed;
+mod write;
 
+pub use config::BlobConfig;
 pub use config::Column;
+pub use config::ColumnOptions;
+pub use config::ColumnOverride;
 pub use config::Config;
 pub use config::StatusField;
+pub use config::StorageSection;
+pub use cache::CachedDagRepository;
+pub use dag::DagFrontier;
 pub use dag::DagRepository;
+pub use db::SnapshotReader;
 pub use db::Storage;
 pub use error::StorageError;
+pub use memory::MemoryDb;
+pub use metrics::StorageMetrics;
+pub use typed::{KeyCodec, TypedColumn, ValueCodec, DAG_BLOCK_COLUMN, GENESIS_COLUMN};
+pub use write::DbWriter;
+pub use write::WriteBatch;
@@ -0,0 +1,213 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use crate::db::{DbIterator, DbReader};
+use crate::write::{DbWriter, WriteBatch};
+use crate::Column;
+
+/// An in-memory [`DbReader`] backend.
+///
+/// Every column family is a `RwLock<BTreeMap<Vec<u8>, Vec<u8>>>`, so ordered
+/// iteration matches the RocksDB byte order without a custom comparator. Unlike
+/// the test-only mock this lives in the crate proper: light nodes that only hold
+/// non-finalized DAG state, deterministic unit tests in other crates, and
+/// simulation harnesses can all drive a [`DagRepository`](crate::DagRepository)
+/// entirely in RAM.
+pub struct MemoryDb {
+    columns: Vec<(Column, RwLock<BTreeMap<Vec<u8>, Vec<u8>>>)>,
+}
+
+impl MemoryDb {
+    /// Creates an empty in-memory database with one map per known column.
+    pub fn new() -> Self {
+        let columns = Column::all()
+            .iter()
+            .map(|col| (*col, RwLock::new(BTreeMap::new())))
+            .collect();
+        MemoryDb { columns }
+    }
+
+    fn column(&self, col: Column) -> &RwLock<BTreeMap<Vec<u8>, Vec<u8>>> {
+        self.columns
+            .iter()
+            .find(|(c, _)| *c == col)
+            .map(|(_, map)| map)
+            .expect("unknown column family")
+    }
+
+    /// Inserts or overwrites `key` in `col`.
+    pub fn put(&self, col: Column, key: &[u8], value: &[u8]) {
+        self.column(col)
+            .write()
+            .unwrap()
+            .insert(key.to_vec(), value.to_vec());
+    }
+
+    /// Removes `key` from `col`, returning whether it was present.
+    pub fn delete(&self, col: Column, key: &[u8]) -> bool {
+        self.column(col).write().unwrap().remove(key).is_some()
+    }
+
+    /// Returns the number of entries in `col`.
+    pub fn len(&self, col: Column) -> usize {
+        self.column(col).read().unwrap().len()
+    }
+
+    /// Returns whether `col` holds no entries.
+    pub fn is_empty(&self, col: Column) -> bool {
+        self.column(col).read().unwrap().is_empty()
+    }
+}
+
+impl Default for MemoryDb {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DbReader for MemoryDb {
+    type Slice<'a> = Vec<u8>;
+
+    fn get<'a>(&'a self, col: Column, key: &[u8]) -> Result<Option<Self::Slice<'a>>> {
+        Ok(self.column(col).read().unwrap().get(key).cloned())
+    }
+
+    fn iter<'a>(&'a self, col: Column) -> DbIterator<'a> {
+        // The read lock cannot outlive this call, so we snapshot the ordered
+        // entries up front, mirroring how the RocksDB iterator yields owned
+        // boxed slices.
+        let items: Vec<_> = self
+            .column(col)
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(k, v)| Ok((k.clone().into_boxed_slice(), v.clone().into_boxed_slice())))
+            .collect();
+        Box::new(items.into_iter())
+    }
+
+    fn iter_rev<'a>(&'a self, col: Column) -> DbIterator<'a> {
+        let items: Vec<_> = self
+            .column(col)
+            .read()
+            .unwrap()
+            .iter()
+            .rev()
+            .map(|(k, v)| Ok((k.clone().into_boxed_slice(), v.clone().into_boxed_slice())))
+            .collect();
+        Box::new(items.into_iter())
+    }
+}
+
+impl DbWriter for MemoryDb {
+    fn put(&self, col: Column, key: &[u8], value: &[u8]) -> Result<()> {
+        MemoryDb::put(self, col, key, value);
+        Ok(())
+    }
+
+    fn delete(&self, col: Column, key: &[u8]) -> Result<()> {
+        MemoryDb::delete(self, col, key);
+        Ok(())
+    }
+
+    fn write_batch(&self, batch: WriteBatch) -> Result<()> {
+        use crate::write::BatchOp;
+        // Hold every column's write lock until the whole batch is applied, so a
+        // reader observes either none of the batch or all of it.
+        let mut maps: Vec<_> = self.columns.iter().map(|(c, m)| (*c, m.write().unwrap())).collect();
+        for op in batch.ops() {
+            match op {
+                BatchOp::Put { col, key, value } => {
+                    let map = &mut maps.iter_mut().find(|(c, _)| c == col).unwrap().1;
+                    map.insert(key.clone(), value.clone());
+                }
+                BatchOp::Delete { col, key } => {
+                    let map = &mut maps.iter_mut().find(|(c, _)| c == col).unwrap().1;
+                    map.remove(key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_put_get_delete() {
+        let db = MemoryDb::new();
+        assert!(db.get(Column::DagBlocks, b"a").unwrap().is_none());
+
+        db.put(Column::DagBlocks, b"a", b"1");
+        assert_eq!(db.get(Column::DagBlocks, b"a").unwrap().unwrap(), b"1");
+
+        assert!(db.delete(Column::DagBlocks, b"a"));
+        assert!(db.get(Column::DagBlocks, b"a").unwrap().is_none());
+        assert!(!db.delete(Column::DagBlocks, b"a"));
+    }
+
+    #[test]
+    fn test_iter_is_ordered() {
+        let db = MemoryDb::new();
+        db.put(Column::DagBlocks, b"c", b"3");
+        db.put(Column::DagBlocks, b"a", b"1");
+        db.put(Column::DagBlocks, b"b", b"2");
+
+        let keys: Vec<_> = db
+            .iter(Column::DagBlocks)
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(keys, vec![b"a".to_vec().into(), b"b".to_vec().into(), b"c".to_vec().into()]);
+
+        let rev: Vec<_> = db
+            .iter_rev(Column::DagBlocks)
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(rev, vec![b"c".to_vec().into(), b"b".to_vec().into(), b"a".to_vec().into()]);
+    }
+
+    #[test]
+    fn test_seek_prefix_and_range() {
+        let db = MemoryDb::new();
+        for key in [b"a1", b"a2", b"b1", b"b2", b"c1"] {
+            db.put(Column::DagBlocks, key, b"v");
+        }
+
+        let from_b: Vec<_> = db
+            .seek(Column::DagBlocks, b"b")
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(
+            from_b,
+            vec![b"b1".to_vec().into(), b"b2".to_vec().into(), b"c1".to_vec().into()]
+        );
+
+        let prefix_a: Vec<_> = db
+            .iter_prefix(Column::DagBlocks, b"a")
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(prefix_a, vec![b"a1".to_vec().into(), b"a2".to_vec().into()]);
+
+        // Range is half-open: "c1" is excluded by the exclusive end bound.
+        let range: Vec<_> = db
+            .iter_range(Column::DagBlocks, b"a2", b"c1")
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(
+            range,
+            vec![b"a2".to_vec().into(), b"b1".to_vec().into(), b"b2".to_vec().into()]
+        );
+
+        let rev_from_b2: Vec<_> = db
+            .seek_rev(Column::DagBlocks, b"b2")
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(
+            rev_from_b2,
+            vec![b"b2".to_vec().into(), b"b1".to_vec().into(), b"a2".to_vec().into(), b"a1".to_vec().into()]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use prometheus::{
+    Histogram, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry,
+};
+
+/// Per-column read-path metrics for the storage layer.
+///
+/// Mirrors the per-store metrics a RocksDB-backed service usually ships: read
+/// latency histograms, get/iter hit and miss counters, and key/value size
+/// summaries, all labeled by [`Column::name`](crate::Column::name). Collection
+/// is gated by [`Config::enable_metrics`](crate::Config); when disabled,
+/// [`Storage`](crate::Storage) holds no [`StorageMetrics`] and the read path
+/// takes none of the recording branches.
+pub struct StorageMetrics {
+    registry: Registry,
+    /// Read latency per operation, labeled by `column` and `op`.
+    latency_seconds: HistogramVec,
+    /// Operation counts, labeled by `column`, `op`, and `outcome` (hit/miss for
+    /// gets, open for iterators).
+    ops_total: IntCounterVec,
+    /// Observed key sizes, labeled by `column`.
+    key_bytes: HistogramVec,
+    /// Observed value sizes, labeled by `column`.
+    value_bytes: HistogramVec,
+}
+
+impl StorageMetrics {
+    /// Builds a fresh metrics set registered against its own [`Registry`].
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let latency_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "storage_read_latency_seconds",
+                "Latency of storage read operations.",
+            ),
+            &["column", "op"],
+        )
+        .expect("valid histogram opts");
+        let ops_total = IntCounterVec::new(
+            Opts::new("storage_read_ops_total", "Count of storage read operations."),
+            &["column", "op", "outcome"],
+        )
+        .expect("valid counter opts");
+        let key_bytes = HistogramVec::new(
+            HistogramOpts::new("storage_key_bytes", "Size of keys touched by reads.")
+                .buckets(size_buckets()),
+            &["column"],
+        )
+        .expect("valid histogram opts");
+        let value_bytes = HistogramVec::new(
+            HistogramOpts::new("storage_value_bytes", "Size of values returned by reads.")
+                .buckets(size_buckets()),
+            &["column"],
+        )
+        .expect("valid histogram opts");
+
+        registry
+            .register(Box::new(latency_seconds.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(ops_total.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(key_bytes.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(value_bytes.clone()))
+            .expect("unique metric");
+
+        StorageMetrics {
+            registry,
+            latency_seconds,
+            ops_total,
+            key_bytes,
+            value_bytes,
+        }
+    }
+
+    /// The registry exposing every storage metric, for scraping.
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Returns the `get` latency histogram for `column`; the guard from its
+    /// `start_timer` records latency when dropped.
+    pub fn start_get(&self, column: &str) -> Histogram {
+        self.latency_seconds.with_label_values(&[column, "get"])
+    }
+
+    /// Records the outcome of a `get`, including key and (on a hit) value sizes.
+    pub fn record_get(&self, column: &str, key_len: usize, value_len: Option<usize>) {
+        self.key_bytes
+            .with_label_values(&[column])
+            .observe(key_len as f64);
+        match value_len {
+            Some(len) => {
+                self.value_bytes
+                    .with_label_values(&[column])
+                    .observe(len as f64);
+                self.ops_total
+                    .with_label_values(&[column, "get", "hit"])
+                    .inc();
+            }
+            None => {
+                self.ops_total
+                    .with_label_values(&[column, "get", "miss"])
+                    .inc();
+            }
+        }
+    }
+
+    /// Records that an iterator (`op` is `iter`, `iter_rev`, `seek`, `seek_rev`,
+    /// `iter_prefix` or `iter_range`) was opened.
+    pub fn record_iter(&self, column: &str, op: &str) {
+        self.ops_total
+            .with_label_values(&[column, op, "open"])
+            .inc();
+    }
+}
+
+impl Default for StorageMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Exponential buckets covering tiny index values up to large blob payloads.
+fn size_buckets() -> Vec<f64> {
+    prometheus::exponential_buckets(8.0, 4.0, 10).expect("valid bucket spec")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Column;
+
+    #[test]
+    fn test_records_hit_and_miss() {
+        let metrics = StorageMetrics::new();
+        metrics.record_get(Column::DagBlocks.name(), 32, Some(128));
+        metrics.record_get(Column::DagBlocks.name(), 32, None);
+        metrics.record_iter(Column::DagBlocks.name(), "iter");
+
+        // Every declared family is present in the registry for scraping.
+        let families = metrics.registry().gather();
+        let names: Vec<_> = families.iter().map(|f| f.get_name().to_string()).collect();
+        assert!(names.contains(&"storage_read_ops_total".to_string()));
+        assert!(names.contains(&"storage_key_bytes".to_string()));
+    }
+}
@@ -0,0 +1,202 @@
+use anyhow::Result;
+use ethereum_types::H256;
+use rustaxa_types::DagBlock;
+use std::marker::PhantomData;
+
+use crate::db::DbReader;
+use crate::write::{DbWriter, WriteBatch};
+use crate::{Column, StorageError};
+
+/// Encodes a key type to and from the raw byte keys stored in a [`Column`].
+///
+/// Integer keys use big-endian so their byte order matches their numeric order,
+/// letting range scans and `iter` walk them in ascending value.
+pub trait KeyCodec: Sized {
+    fn encode_key(&self) -> Vec<u8>;
+    fn decode_key(bytes: &[u8]) -> Result<Self>;
+}
+
+/// Encodes a value type to and from the raw byte values stored in a [`Column`].
+pub trait ValueCodec: Sized {
+    fn encode_value(&self) -> Vec<u8>;
+    fn decode_value(bytes: &[u8]) -> Result<Self>;
+}
+
+impl KeyCodec for H256 {
+    fn encode_key(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != 32 {
+            return Err(StorageError::Read(format!(
+                "H256 key is {} bytes, expected 32",
+                bytes.len()
+            ))
+            .into());
+        }
+        Ok(H256::from_slice(bytes))
+    }
+}
+
+impl KeyCodec for u64 {
+    fn encode_key(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Result<Self> {
+        let arr: [u8; 8] = bytes.try_into().map_err(|_| {
+            StorageError::Read(format!("u64 key is {} bytes, expected 8", bytes.len()))
+        })?;
+        Ok(u64::from_be_bytes(arr))
+    }
+}
+
+impl KeyCodec for i32 {
+    fn encode_key(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Result<Self> {
+        let arr: [u8; 4] = bytes.try_into().map_err(|_| {
+            StorageError::Read(format!("i32 key is {} bytes, expected 4", bytes.len()))
+        })?;
+        Ok(i32::from_be_bytes(arr))
+    }
+}
+
+impl ValueCodec for H256 {
+    fn encode_value(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != 32 {
+            return Err(StorageError::Read(format!(
+                "H256 value is {} bytes, expected 32",
+                bytes.len()
+            ))
+            .into());
+        }
+        Ok(H256::from_slice(bytes))
+    }
+}
+
+impl ValueCodec for DagBlock {
+    fn encode_value(&self) -> Vec<u8> {
+        self.to_rlp_bytes()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Result<Self> {
+        Ok(DagBlock::from_rlp_bytes(bytes)?)
+    }
+}
+
+/// A typed view over a single [`Column`], pairing a [`KeyCodec`] with a
+/// [`ValueCodec`] so callers work in terms of `K`/`V` instead of raw slices.
+///
+/// Declaring a schema is a one-liner — see [`DAG_BLOCK_COLUMN`] — and reusing
+/// the existing codecs keeps `H256::from_slice`/`from_rlp_bytes` in one place
+/// rather than scattered across every call site.
+pub struct TypedColumn<K, V> {
+    column: Column,
+    _marker: PhantomData<fn(K) -> V>,
+}
+
+impl<K: KeyCodec, V: ValueCodec> TypedColumn<K, V> {
+    pub const fn new(column: Column) -> Self {
+        TypedColumn {
+            column,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The underlying raw column.
+    pub fn column(&self) -> Column {
+        self.column
+    }
+
+    /// Reads and decodes the value at `key`, if present.
+    pub fn get<D: DbReader>(&self, db: &D, key: &K) -> Result<Option<V>> {
+        match db.get(self.column, &key.encode_key())? {
+            Some(bytes) => Ok(Some(V::decode_value(bytes.as_ref())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Writes `value` under `key`.
+    pub fn put<D: DbWriter>(&self, db: &D, key: &K, value: &V) -> Result<()> {
+        db.put(self.column, &key.encode_key(), &value.encode_value())
+    }
+
+    /// Removes `key`.
+    pub fn delete<D: DbWriter>(&self, db: &D, key: &K) -> Result<()> {
+        db.delete(self.column, &key.encode_key())
+    }
+
+    /// Queues a put of `key` -> `value` into `batch` for an atomic commit.
+    pub fn put_batch(&self, batch: &mut WriteBatch, key: &K, value: &V) {
+        batch.put(self.column, &key.encode_key(), &value.encode_value());
+    }
+
+    /// Iterates the column in key order, decoding each entry.
+    pub fn iter<'a, D: DbReader>(
+        &self,
+        db: &'a D,
+    ) -> impl Iterator<Item = Result<(K, V)>> + 'a {
+        db.iter(self.column).map(|res| {
+            let (key, value) = res?;
+            Ok((K::decode_key(&key)?, V::decode_value(&value)?))
+        })
+    }
+}
+
+/// The non-finalized DAG block store, keyed by block hash.
+pub const DAG_BLOCK_COLUMN: TypedColumn<H256, DagBlock> = TypedColumn::new(Column::DagBlocks);
+
+/// The genesis hash, stored under the single status key `0`.
+pub const GENESIS_COLUMN: TypedColumn<i32, H256> = TypedColumn::new(Column::Genesis);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryDb;
+
+    #[test]
+    fn test_level_keys_sort_numerically() {
+        // Big-endian keys order numerically under byte comparison, unlike the
+        // little-endian keys used by the raw index.
+        assert!(9u64.encode_key() < 10u64.encode_key());
+        assert!(255u64.encode_key() < 256u64.encode_key());
+        assert_eq!(u64::decode_key(&42u64.encode_key()).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_typed_column_round_trip() {
+        let db = MemoryDb::new();
+        let hash = H256::random();
+        let block =
+            DagBlock::from_rlp_bytes(&dummy_block_rlp()).expect("valid block encoding");
+
+        assert!(DAG_BLOCK_COLUMN.get(&db, &hash).unwrap().is_none());
+        DAG_BLOCK_COLUMN.put(&db, &hash, &block).unwrap();
+        assert_eq!(DAG_BLOCK_COLUMN.get(&db, &hash).unwrap().unwrap(), block);
+
+        let collected: Vec<_> = DAG_BLOCK_COLUMN.iter(&db).map(|r| r.unwrap()).collect();
+        assert_eq!(collected, vec![(hash, block)]);
+    }
+
+    fn dummy_block_rlp() -> Vec<u8> {
+        use rlp::RlpStream;
+        let mut stream = RlpStream::new_list(8);
+        stream.append(&H256::zero());
+        stream.append(&10u64);
+        stream.append(&123456789u64);
+        stream.append(&vec![1u8, 2, 3]);
+        stream.begin_list(0);
+        stream.begin_list(0);
+        stream.append(&vec![0u8; 65]);
+        stream.append(&1000u64);
+        stream.out().to_vec()
+    }
+}
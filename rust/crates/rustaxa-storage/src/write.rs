@@ -0,0 +1,123 @@
+use anyhow::Result;
+use rocksdb::{DBWithThreadMode, MultiThreaded};
+
+use crate::Column;
+use crate::StorageError;
+
+/// A single mutation queued in a [`WriteBatch`], tagged with its target column.
+pub(crate) enum BatchOp {
+    Put {
+        col: Column,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    Delete {
+        col: Column,
+        key: Vec<u8>,
+    },
+}
+
+/// An ordered set of puts and deletes spanning any number of column families,
+/// applied atomically by [`DbWriter::write_batch`].
+///
+/// The operations are replayed in insertion order into a single RocksDB
+/// `WriteBatch`, so either all of them land or none do — the unit a caller
+/// needs when an index entry and its block must stay consistent.
+#[derive(Default)]
+pub struct WriteBatch {
+    ops: Vec<BatchOp>,
+}
+
+impl WriteBatch {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        WriteBatch { ops: Vec::new() }
+    }
+
+    /// Queues a put of `key` -> `value` in `col`.
+    pub fn put(&mut self, col: Column, key: &[u8], value: &[u8]) -> &mut Self {
+        self.ops.push(BatchOp::Put {
+            col,
+            key: key.to_vec(),
+            value: value.to_vec(),
+        });
+        self
+    }
+
+    /// Queues a delete of `key` in `col`.
+    pub fn delete(&mut self, col: Column, key: &[u8]) -> &mut Self {
+        self.ops.push(BatchOp::Delete {
+            col,
+            key: key.to_vec(),
+        });
+        self
+    }
+
+    /// Returns whether the batch holds no operations.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Returns the number of queued operations.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// The queued operations in insertion order, for backends that replay them
+    /// without a native RocksDB batch.
+    pub(crate) fn ops(&self) -> &[BatchOp] {
+        &self.ops
+    }
+}
+
+/// Trait abstracting database write operations, the mutating counterpart of
+/// [`DbReader`](crate::DbReader).
+pub trait DbWriter: Send + Sync {
+    /// Writes `key` -> `value` in `col`.
+    fn put(&self, col: Column, key: &[u8], value: &[u8]) -> Result<()>;
+
+    /// Removes `key` from `col`.
+    fn delete(&self, col: Column, key: &[u8]) -> Result<()>;
+
+    /// Applies every operation in `batch` atomically across its column families.
+    fn write_batch(&self, batch: WriteBatch) -> Result<()>;
+}
+
+impl DbWriter for DBWithThreadMode<MultiThreaded> {
+    fn put(&self, col: Column, key: &[u8], value: &[u8]) -> Result<()> {
+        let handle = cf_handle(self, col)?;
+        self.put_cf(&handle, key, value)
+            .map_err(|e| StorageError::Database(e).into())
+    }
+
+    fn delete(&self, col: Column, key: &[u8]) -> Result<()> {
+        let handle = cf_handle(self, col)?;
+        self.delete_cf(&handle, key)
+            .map_err(|e| StorageError::Database(e).into())
+    }
+
+    fn write_batch(&self, batch: WriteBatch) -> Result<()> {
+        let mut raw = rocksdb::WriteBatch::default();
+        for op in &batch.ops {
+            match op {
+                BatchOp::Put { col, key, value } => {
+                    raw.put_cf(&cf_handle(self, *col)?, key, value);
+                }
+                BatchOp::Delete { col, key } => {
+                    raw.delete_cf(&cf_handle(self, *col)?, key);
+                }
+            }
+        }
+        self.write(raw).map_err(|e| StorageError::Database(e).into())
+    }
+}
+
+/// Resolves the column-family handle for `col`, surfacing a missing family as a
+/// configuration error.
+fn cf_handle(
+    db: &DBWithThreadMode<MultiThreaded>,
+    col: Column,
+) -> Result<std::sync::Arc<rocksdb::BoundColumnFamily<'_>>> {
+    db.cf_handle(col.name())
+        .ok_or_else(|| StorageError::Config(format!("Missing column family: {}", col.name())).into())
+}
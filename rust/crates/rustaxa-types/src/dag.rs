@@ -1,6 +1,22 @@
 use anyhow::{Result, anyhow};
 use ethereum_types::H256;
-use rlp::{Decodable, DecoderError, Rlp};
+use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+use rustaxa_vdf::vdf::{Solution, WesolowskiVdf};
+use rustaxa_vdf::verifier::WesolowskiVerifier;
+
+/// Security parameter for the hash-to-prime challenge of the block VDF. The
+/// prover and verifier must agree on it, so it is fixed by the block format
+/// rather than carried in each block.
+pub const VDF_LAMBDA: u32 = 256;
+
+/// The delay parameter `log2(T)` every block's VDF must use. Like
+/// [`VDF_LAMBDA`], this is a protocol constant: if proposers could pick their
+/// own `time_bits`, a malicious proposer could declare a tiny value (or 0) and
+/// produce a "proof" in microseconds, defeating the verifiable delay the DAG
+/// relies on for block pacing. The field is still carried in the RLP payload
+/// so the hash-to-prime transcript layout is unchanged, but [`DagBlock::decode_vdf`]
+/// rejects any block that doesn't declare exactly this value.
+pub const VDF_TIME_BITS: u32 = 20;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DagBlock {
@@ -19,6 +35,67 @@ impl DagBlock {
         let rlp = Rlp::new(bytes);
         Self::decode(&rlp).map_err(|e| anyhow!("RLP decode error: {}", e))
     }
+
+    pub fn to_rlp_bytes(&self) -> Vec<u8> {
+        rlp::encode(self).to_vec()
+    }
+
+    /// Decodes the `vdf` field into the delay parameter `time_bits` and the
+    /// Wesolowski [`Solution`] `(π, y)`. The payload is the RLP list
+    /// `[time_bits, π, y]` a proposer writes after solving the puzzle.
+    ///
+    /// `time_bits` is untrusted proposer input, so it is checked against the
+    /// protocol-fixed [`VDF_TIME_BITS`] here rather than trusted as free-form:
+    /// a proposer who could pick their own delay could declare `time_bits = 0`
+    /// and skip the verifiable delay entirely.
+    fn decode_vdf(&self) -> Result<(u32, Solution)> {
+        let rlp = Rlp::new(&self.vdf);
+        let time_bits: u32 = rlp.val_at(0).map_err(|e| anyhow!("VDF decode error: {}", e))?;
+        if time_bits != VDF_TIME_BITS {
+            return Err(anyhow!(
+                "VDF decode error: time_bits {} does not match the protocol value {}",
+                time_bits,
+                VDF_TIME_BITS
+            ));
+        }
+        let first: Vec<u8> = rlp.val_at(1).map_err(|e| anyhow!("VDF decode error: {}", e))?;
+        let second: Vec<u8> = rlp.val_at(2).map_err(|e| anyhow!("VDF decode error: {}", e))?;
+        Ok((time_bits, Solution { first, second }))
+    }
+
+    /// Verifies the block's proof-of-delay against the shared RSA `modulus`.
+    ///
+    /// The VDF base `g` is derived from the block's prior-anchor (pivot) hash,
+    /// so every block commits to a distinct puzzle; `modulus` and the
+    /// protocol-fixed [`VDF_TIME_BITS`] complete the public parameters. Returns
+    /// `false` for a missing, malformed, invalid, or wrong-`time_bits` proof.
+    pub fn verify_vdf(&self, modulus: &[u8]) -> bool {
+        let (time_bits, solution) = match self.decode_vdf() {
+            Ok(parts) => parts,
+            Err(_) => return false,
+        };
+        let vdf = WesolowskiVdf::new(
+            VDF_LAMBDA,
+            time_bits,
+            self.pivot.as_bytes().to_vec(),
+            modulus.to_vec(),
+        );
+        WesolowskiVerifier::new(&vdf).verify(&solution)
+    }
+}
+
+impl Encodable for DagBlock {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.begin_list(8);
+        stream.append(&self.pivot);
+        stream.append(&self.level);
+        stream.append(&self.timestamp);
+        stream.append(&self.vdf);
+        stream.append_list(&self.tips);
+        stream.append_list(&self.transactions);
+        stream.append(&self.signature.as_slice());
+        stream.append(&self.gas_estimation);
+    }
 }
 
 impl Decodable for DagBlock {
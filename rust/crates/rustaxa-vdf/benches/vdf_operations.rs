@@ -48,5 +48,81 @@ fn bench_vdf_hash_to_prime(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_vdf_creation, bench_vdf_hash_to_prime);
+/// Combined two-base exponentiation `g^r · π^p mod N` via Shamir's trick,
+/// mirroring the `multi_exp_two` path the verifier now uses.
+fn combined_exp(g: &Integer, r: &Integer, pi: &Integer, p: &Integer, n: &Integer) -> Integer {
+    let b1 = Integer::from(g % n);
+    let b2 = Integer::from(pi % n);
+    let mut b12 = Integer::from(&b1 * &b2);
+    b12 %= n;
+    let table = [Integer::from(1), b1, b2, b12];
+
+    let bits = r.significant_bits().max(p.significant_bits());
+    let mut acc = Integer::from(1);
+    for i in (0..bits).rev() {
+        acc.square_mut();
+        acc %= n;
+        let index = ((r.get_bit(i) as usize) << 1) | (p.get_bit(i) as usize);
+        if index != 0 {
+            acc *= &table[index];
+            acc %= n;
+        }
+    }
+    acc
+}
+
+/// Current two-call path: two independent `pow_mod`s multiplied together.
+fn two_call_exp(g: &Integer, r: &Integer, pi: &Integer, p: &Integer, n: &Integer) -> Integer {
+    let mut out = g.clone().pow_mod(r, n).unwrap();
+    out *= pi.clone().pow_mod(p, n).unwrap();
+    out %= n;
+    out
+}
+
+fn bench_verify_multi_exp(c: &mut Criterion) {
+    let mut group = c.benchmark_group("verify_multi_exp");
+
+    // A modulus and exponents wide enough for the squaring chain to dominate.
+    let n = Integer::from_str_radix(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639747",
+        10,
+    )
+    .unwrap();
+    let g = Integer::from(5u32);
+    let pi = Integer::from(7u32);
+    let r = Integer::from(&n - 9);
+    let p = Integer::from(&n - 123);
+
+    group.bench_function("combined", |b| {
+        b.iter(|| {
+            black_box(combined_exp(
+                black_box(&g),
+                black_box(&r),
+                black_box(&pi),
+                black_box(&p),
+                black_box(&n),
+            ))
+        })
+    });
+    group.bench_function("two_call", |b| {
+        b.iter(|| {
+            black_box(two_call_exp(
+                black_box(&g),
+                black_box(&r),
+                black_box(&pi),
+                black_box(&p),
+                black_box(&n),
+            ))
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_vdf_creation,
+    bench_vdf_hash_to_prime,
+    bench_verify_multi_exp
+);
 criterion_main!(benches);
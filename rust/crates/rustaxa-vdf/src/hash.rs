@@ -1,15 +1,100 @@
 use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock};
 
 // Simple cache for precision bounds to avoid recomputing for common lambda values
 static PRECISION_CACHE: OnceLock<Mutex<HashMap<u32, rug::Integer>>> = OnceLock::new();
 
+// Small-prime sieves are shared between instances the same way: computing the
+// table up to a bound is pure, so it is memoized by bound and cloned out.
+static SIEVE_CACHE: OnceLock<Mutex<HashMap<u64, Arc<Vec<u64>>>>> = OnceLock::new();
+
+/// Default trial-division bound: sieve every prime below 2^16.
+pub const DEFAULT_SIEVE_BOUND: u64 = 1 << 16;
+
+/// Selects the hashing strategy used to derive the challenge prime.
+///
+/// The default [`HashBackend::Keccak256`] feeds the full big-endian encoding of
+/// the input through Keccak-256 in counter mode — an XOF-style construction that
+/// consumes every bit of the transcript, so distinct challenges cannot collide
+/// onto the same `l`. It is also the EVM-native hash, so the Rust prover, the
+/// Rust verifier and a generated on-chain verifier all derive identical `l`
+/// values for the same `(g, y, T)` input.
+///
+/// [`HashBackend::Lambert`] is the legacy RandState-seeded `6k±1` rejection
+/// sampler. It collapses the input into a 64-bit seed and is therefore *not*
+/// collision-resistant, so it is gated behind the non-cryptographic
+/// `insecure-rand` feature for fast tests only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashBackend {
+    #[cfg(feature = "insecure-rand")]
+    Lambert,
+    Keccak256,
+}
+
+impl Default for HashBackend {
+    fn default() -> Self {
+        HashBackend::Keccak256
+    }
+}
+
 pub struct HashToPrime {
     max_int: rug::Integer,
+    backend: HashBackend,
+    /// Primes below `sieve_bound`, used as a cheap trial-division prefilter
+    /// before the expensive probabilistic primality test.
+    small_primes: Arc<Vec<u64>>,
+    sieve_bound: u64,
+    /// Extra Miller–Rabin rounds layered on top of the default Baillie–PSW
+    /// acceptance test. Zero — the default — relies on BPSW alone, which has no
+    /// known pseudoprimes; a non-zero value adds that many random MR bases for
+    /// callers who want additional margin.
+    mr_rounds: u32,
+    /// Target bit-length of the emitted prime. Defaults to `2·lambda`, the
+    /// regime Wesolowski's soundness needs, rather than any fixed machine-word
+    /// clamp.
+    prime_bits: u32,
 }
 
 impl HashToPrime {
     pub fn new(lambda: u32) -> Self {
+        Self::with_backend(lambda, HashBackend::default())
+    }
+
+    /// Creates a hash-to-prime instance with an explicit hashing backend.
+    pub fn with_backend(lambda: u32, backend: HashBackend) -> Self {
+        Self::build(lambda, backend, DEFAULT_SIEVE_BOUND)
+    }
+
+    /// Creates a hash-to-prime instance with a custom trial-division bound `B`.
+    /// Larger `B` rejects more composites cheaply at the cost of a bigger sieve;
+    /// the generated primes are identical regardless of `B`.
+    pub fn with_sieve_bound(lambda: u32, sieve_bound: u64) -> Self {
+        Self::build(lambda, HashBackend::default(), sieve_bound)
+    }
+
+    /// Creates an instance whose candidate sieve uses only the first
+    /// `n_small_primes` primes, letting the caller tune the pre-filter cost
+    /// against its hit rate. A larger table rejects more composites before the
+    /// BPSW test but costs more per wheel step. The generated primes are
+    /// identical regardless of the limit.
+    pub fn with_sieve_limit(lambda: u32, n_small_primes: usize) -> Self {
+        let mut h2p = Self::build(lambda, HashBackend::default(), DEFAULT_SIEVE_BOUND);
+        if n_small_primes < h2p.small_primes.len() {
+            h2p.small_primes = Arc::new(h2p.small_primes[..n_small_primes].to_vec());
+        }
+        h2p
+    }
+
+    /// Creates an instance that layers `mr_rounds` extra Miller–Rabin bases on
+    /// top of the default Baillie–PSW acceptance test. `mr_rounds = 0` is the
+    /// default BPSW-only behaviour.
+    pub fn with_mr_rounds(lambda: u32, mr_rounds: u32) -> Self {
+        let mut h2p = Self::build(lambda, HashBackend::default(), DEFAULT_SIEVE_BOUND);
+        h2p.mr_rounds = mr_rounds;
+        h2p
+    }
+
+    fn build(lambda: u32, backend: HashBackend, sieve_bound: u64) -> Self {
         let max_int = {
             // Check cache first for common lambda values
             let cache = PRECISION_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
@@ -39,7 +124,74 @@ impl HashToPrime {
 
         HashToPrime {
             max_int: optimized_max_int,
+            backend,
+            small_primes: sieve_primes(sieve_bound),
+            sieve_bound,
+            mr_rounds: 0,
+            // Wesolowski's challenge prime must be ~2λ bits for soundness.
+            prime_bits: lambda.saturating_mul(2).max(64),
+        }
+    }
+
+    /// Creates an instance emitting primes of the given target `bits` length,
+    /// overriding the default `2·lambda` regime. This decouples the challenge
+    /// prime width from any machine-word clamp so it scales with the security
+    /// parameter.
+    pub fn with_prime_bits(lambda: u32, bits: u32) -> Self {
+        let mut h2p = Self::build(lambda, HashBackend::default(), DEFAULT_SIEVE_BOUND);
+        h2p.prime_bits = bits.max(64);
+        h2p
+    }
+
+    /// Returns the target bit-length of the emitted prime.
+    pub fn prime_bits(&self) -> u32 {
+        self.prime_bits
+    }
+
+    /// Acceptance test for a generated candidate: the default Baillie–PSW check
+    /// plus any extra Miller–Rabin rounds requested via
+    /// [`with_mr_rounds`](Self::with_mr_rounds).
+    fn accept(&self, candidate: &rug::Integer) -> bool {
+        use rug::integer::IsPrime;
+        if !is_prime(candidate) {
+            return false;
+        }
+        self.mr_rounds == 0 || candidate.is_probably_prime(self.mr_rounds) != IsPrime::No
+    }
+
+    /// Returns the small-prime trial-division bound.
+    pub fn sieve_bound(&self) -> u64 {
+        self.sieve_bound
+    }
+
+    /// Exact, allocation-free primality test for machine-word candidates.
+    ///
+    /// For any `n < 2^64` this replaces the ten-round probabilistic test with a
+    /// deterministic strong-probable-prime check over the smallest proven witness
+    /// set for the candidate's magnitude (a single base below 2047, widening to
+    /// seven bases from `3.47 × 10^12` up). The arithmetic runs entirely in `u64`/`u128`
+    /// — no `rug` allocation — and returns a definite yes/no. Even candidates and
+    /// `n < 2` are handled directly, and the small primes 2, 3 and 5 short-circuit
+    /// before any modular exponentiation.
+    pub fn is_prime_u64(n: u64) -> bool {
+        if n < 2 {
+            return false;
+        }
+        for &p in &[2u64, 3, 5] {
+            if n == p {
+                return true;
+            }
+            if n % p == 0 {
+                return false;
+            }
         }
+        let witnesses = deterministic_witnesses_u64(n);
+        strong_probable_prime_u64(n, witnesses)
+    }
+
+    /// Returns the hashing backend in use.
+    pub fn backend(&self) -> HashBackend {
+        self.backend
     }
 
     /// Get the maximum integer bound used for prime generation
@@ -49,21 +201,132 @@ impl HashToPrime {
     }
 
     pub fn hash_to_prime(&self, input: &rug::Integer) -> Result<rug::Integer, String> {
-        use rug::integer::IsPrime;
+        match self.backend {
+            #[cfg(feature = "insecure-rand")]
+            HashBackend::Lambert => self.hash_to_prime_lambert(input),
+            HashBackend::Keccak256 => self.hash_to_prime_keccak256(input),
+        }
+    }
+
+    /// Like [`hash_to_prime`](Self::hash_to_prime) but also reports whether the
+    /// returned prime was certified by the deterministic Miller–Rabin path
+    /// (`true`) or only passed the probabilistic test (`false`).
+    pub fn hash_to_prime_certified(
+        &self,
+        input: &rug::Integer,
+    ) -> Result<(rug::Integer, bool), String> {
+        let prime = self.hash_to_prime(input)?;
+        let certified = deterministic_witnesses(&prime).is_some();
+        Ok((prime, certified))
+    }
+
+    /// Hashes many transcripts in one call, screening every input's candidate
+    /// window with a shared segmented sieve before any Miller–Rabin test.
+    ///
+    /// Each input seeds a search at [`keccak_seed`](Self::keccak_seed); the odd
+    /// candidates above it are screened in fixed-width segments by striking the
+    /// multiples of the shared small-prime table (`self.small_primes`) across
+    /// the whole segment at once — the classic segmented Sieve of Eratosthenes,
+    /// done over the big-integer window rather than a `u64` range — and only the
+    /// survivors reach [`accept`](Self::accept). The sieve marks exactly the
+    /// candidates the per-input trial-division prefilter would skip, so the
+    /// batch results match [`hash_to_prime`](Self::hash_to_prime) element for
+    /// element; the win is that the small-prime crossing-off is shared across a
+    /// whole window instead of recomputed per candidate.
+    pub fn hash_to_prime_batch(
+        &self,
+        inputs: &[rug::Integer],
+    ) -> Vec<Result<rug::Integer, String>> {
+        match self.backend {
+            #[cfg(feature = "insecure-rand")]
+            HashBackend::Lambert => {
+                // The Lambert backend draws a random start base, so it has no
+                // contiguous candidate window to sieve; fall back per input.
+                return inputs.iter().map(|i| self.hash_to_prime(i)).collect();
+            }
+            HashBackend::Keccak256 => {}
+        }
+        inputs
+            .iter()
+            .map(|input| self.keccak_prime_sieved(input))
+            .collect()
+    }
+
+    /// Keccak hash-to-prime driven by a shared segmented sieve over the seed's
+    /// candidate window. Returns the first odd candidate above the seed that
+    /// survives small-prime screening and [`accept`](Self::accept) — the same
+    /// prime the per-candidate search in
+    /// [`hash_to_prime_keccak256`](Self::hash_to_prime_keccak256) finds.
+    fn keccak_prime_sieved(&self, input: &rug::Integer) -> Result<rug::Integer, String> {
+        const MAX_ITER: u32 = 10000;
+        // Odd candidates per segment; wide enough that one segment usually
+        // contains the prime yet small enough to keep the bitmap cheap.
+        const SEGMENT: u64 = 2048;
+
+        let base = self.keccak_seed(input);
+        let mut tested: u32 = 0;
+
+        loop {
+            // `segment[i]` screens the candidate `base + 2·(offset + i)`.
+            let offset = u64::from(tested);
+            let mut composite = vec![false; SEGMENT as usize];
+            let segment_start = rug::Integer::from(&base + 2 * offset);
+
+            for &p in self.small_primes.iter() {
+                if p == 2 {
+                    // Every candidate is odd, so 2 never strikes.
+                    continue;
+                }
+                // Strike candidates divisible by p: solve
+                // `2·i ≡ -start (mod p)`, i.e. `i ≡ -start · 2⁻¹ (mod p)`.
+                let start_res = segment_start.mod_u(p as u32) as u64;
+                let inv2 = (p + 1) / 2; // 2⁻¹ mod p for odd p
+                let mut i = ((p - start_res % p) % p) * (inv2 % p) % p;
+                // The candidates dwarf every small prime, so a struck candidate
+                // is never the prime itself — no exception needed.
+                while i < SEGMENT {
+                    composite[i as usize] = true;
+                    i += p;
+                }
+            }
+
+            for (i, &is_comp) in composite.iter().enumerate() {
+                if is_comp {
+                    continue;
+                }
+                let candidate = rug::Integer::from(&segment_start + 2 * i as u64);
+                if self.accept(&candidate) {
+                    return Ok(candidate);
+                }
+            }
+
+            tested += SEGMENT as u32;
+            if tested >= MAX_ITER {
+                return Err(format!(
+                    "Prime not found within {} iterations for target_bits={}",
+                    MAX_ITER, self.prime_bits
+                ));
+            }
+        }
+    }
+
+    #[cfg(feature = "insecure-rand")]
+    fn hash_to_prime_lambert(&self, input: &rug::Integer) -> Result<rug::Integer, String> {
         use rug::ops::Pow;
 
         const MAX_ITER: u32 = 10000;
-        const MAX_MILLER_RABIN: u32 = 30;
 
         // Use a practical range for hash-to-prime operations
         // Start with a small, manageable range that's likely to contain primes
         // Optimization: Use bit operations instead of pow for powers of 2
-        let practical_bits = std::cmp::min(self.max_int.significant_bits(), 32);
+        let practical_bits = self.prime_bits;
+        // The candidate is `30 * base + residue`, so the random base ranges over
+        // the bound divided by the wheel modulus rather than by 6.
         let practical_max: rug::Integer = if practical_bits <= 64 {
             // For small bit sizes, use more efficient bit shifting
-            rug::Integer::from(1u64 << std::cmp::min(practical_bits, 63)) / 6
+            rug::Integer::from(1u64 << std::cmp::min(practical_bits, 63)) / 30
         } else {
-            rug::Integer::from(2u32).pow(practical_bits) / 6
+            rug::Integer::from(2u32).pow(practical_bits) / 30
         };
 
         // Convert input integer to bytes and seed the random generator directly
@@ -81,48 +344,494 @@ impl HashToPrime {
         let mut prime_gen = rug::rand::RandState::new();
         prime_gen.seed(&seed);
 
-        let mut is_prime = IsPrime::No;
+        let thirty = WHEEL_MODULUS as u64;
+
+        // Single random start base; the search then walks the wheel interval
+        // upward rather than re-drawing an independent base each iteration.
+        let start_base = practical_max.clone().random_below(&mut prime_gen);
+
+        // Residue of `30·base` against each sieved small prime, advanced by a
+        // cheap add-and-reduce (`+30 mod p`) as `base` increments. A candidate
+        // `30·base + r` is divisible by `p` exactly when `(res + r) mod p == 0`.
+        let mut base30_res: Vec<u64> = self
+            .small_primes
+            .iter()
+            .map(|&p| rug::Integer::from(&start_base * thirty).mod_u(p as u32) as u64)
+            .collect();
+        // Below this bound a zero residue may be the small prime itself, so the
+        // sieve must not reject it; such candidates are tested directly.
+        let max_small = self.small_primes.last().copied().unwrap_or(1);
+
+        let mut base = start_base;
         let mut count = 0u32;
-        let mut candidate = rug::Integer::new();
+        while count < MAX_ITER {
+            for &residue in WHEEL_RESIDUES.iter() {
+                count += 1;
+                if count > MAX_ITER {
+                    break;
+                }
+                let candidate = rug::Integer::from(&base * thirty) + residue;
+                if candidate < 2 {
+                    continue;
+                }
+                // Sieve: skip candidates with a small factor (unless small enough
+                // to be a sieved prime, which is then verified directly).
+                let has_small_factor = base30_res
+                    .iter()
+                    .zip(self.small_primes.iter())
+                    .any(|(&res, &p)| (res + residue as u64) % p == 0);
+                if has_small_factor && candidate > max_small {
+                    continue;
+                }
+                if self.accept(&candidate) {
+                    return Ok(candidate);
+                }
+            }
+            base += 1;
+            for (res, &p) in base30_res.iter_mut().zip(self.small_primes.iter()) {
+                *res = (*res + thirty) % p;
+            }
+        }
+
+        Err(format!(
+            "Prime not found within {} iterations for practical_bits={}",
+            MAX_ITER, practical_bits
+        ))
+    }
+
+    /// Derives the starting candidate for the Keccak hash-to-prime search: a
+    /// big-endian integer read from the Keccak-256 counter-mode digest stream
+    /// `keccak256(be(input) || counter)`, with bit `prime_bits - 1` forced set
+    /// (so the realized width is exactly `prime_bits`) and the low bit forced
+    /// odd. Both the per-input and the batched search step upward from here.
+    fn keccak_seed(&self, input: &rug::Integer) -> rug::Integer {
+        let target_bits = self.prime_bits;
+        let byte_len = target_bits.div_ceil(8) as usize;
+
+        // Big-endian encoding of the input, matching `abi.encodePacked`.
+        let input_bytes = if input.is_zero() {
+            vec![0u8]
+        } else {
+            input.to_digits::<u8>(rug::integer::Order::MsfBe)
+        };
+
+        // Fill `byte_len` bytes from the Keccak-256 counter-mode digest stream.
+        let mut digest_stream = Vec::with_capacity(byte_len.div_ceil(32) * 32);
+        let mut counter: u32 = 0;
+        while digest_stream.len() < byte_len {
+            let mut block = [0u8; 32];
+            keccak256_into(&[&input_bytes, &counter.to_be_bytes()], &mut block);
+            digest_stream.extend_from_slice(&block);
+            counter += 1;
+        }
+        digest_stream.truncate(byte_len);
+
+        let mut candidate = rug::Integer::from_digits(&digest_stream, rug::integer::Order::MsfBe);
+        candidate.set_bit(target_bits - 1, true);
+        if candidate.is_even() {
+            candidate += 1;
+        }
+        candidate
+    }
 
-        // Pre-allocate constants to avoid repeated allocations in the loop
-        let six = rug::Integer::from(6);
-        let one = rug::Integer::from(1);
+    /// Keccak-256 hash-to-prime: derive the candidate from a Keccak-256 digest
+    /// stream over the normalized big-endian encoding of `input`, then step
+    /// upward over odd candidates (nextprime) to reach a `prime_bits`-bit prime.
+    ///
+    /// The digest stream is produced in counter mode — `keccak256(be(input) ||
+    /// counter)` — concatenating blocks until enough bytes are available to fill
+    /// the target prime width. The encoding and byte order are chosen so that
+    /// the exact same `l` can be recomputed inside a Solidity verifier.
+    fn hash_to_prime_keccak256(&self, input: &rug::Integer) -> Result<rug::Integer, String> {
+        const MAX_ITER: u32 = 10000;
+
+        let target_bits = self.prime_bits;
+        let mut candidate = self.keccak_seed(input);
+
+        // Incremental trial-division residues against the small-prime table:
+        // `residues[i] = candidate mod p_i`, advanced by a cheap add-and-reduce
+        // as the candidate steps by two. Any zero residue (for a candidate
+        // larger than that prime) means a small factor, so the costly
+        // Miller-Rabin test is skipped — composites dominate the search.
+        let mut residues: Vec<u64> = self
+            .small_primes
+            .iter()
+            .map(|&p| candidate.mod_u(p as u32) as u64)
+            .collect();
+
+        // nextprime by stepping over odd candidates until a probable prime.
         let two = rug::Integer::from(2);
+        let mut count = 0u32;
+        loop {
+            let has_small_factor = residues
+                .iter()
+                .zip(self.small_primes.iter())
+                .any(|(&r, &p)| r == 0 && candidate != p);
+            if !has_small_factor && self.accept(&candidate) {
+                break;
+            }
+            candidate += &two;
+            count += 1;
+            if count >= MAX_ITER {
+                return Err(format!(
+                    "Prime not found within {} iterations for target_bits={}",
+                    MAX_ITER, target_bits
+                ));
+            }
+            for (r, &p) in residues.iter_mut().zip(self.small_primes.iter()) {
+                *r = (*r + 2) % p;
+            }
+        }
+
+        Ok(candidate)
+    }
+}
 
-        while is_prime != IsPrime::Yes && count < MAX_ITER {
-            // Generate random candidate in range [0, practical_max)
-            candidate = practical_max.clone().random_below(&mut prime_gen);
+/// Wheel modulus `2·3·5`; candidates are drawn from the residue classes coprime
+/// to it, skipping every multiple of 2, 3 and 5 (~73% of integers). Used by the
+/// Lambert wheel search and the wheel-residue tests.
+#[cfg(any(feature = "insecure-rand", test))]
+const WHEEL_MODULUS: u32 = 30;
+/// The eight residues modulo [`WHEEL_MODULUS`] coprime to 30.
+#[cfg(any(feature = "insecure-rand", test))]
+const WHEEL_RESIDUES: [u32; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
+
+/// Proven witness set for `n < 3_215_031_751`.
+const DET_SMALL_WITNESSES: [u32; 4] = [2, 3, 5, 7];
+/// Proven witness set (first twelve primes) for `n < 3.317 × 10^24`.
+const DET_LARGE_WITNESSES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Returns the deterministic Miller–Rabin witness set that certifies `n`, or
+/// `None` when `n` is above every proven bound and only probabilistic testing
+/// applies.
+fn deterministic_witnesses(n: &rug::Integer) -> Option<&'static [u32]> {
+    let small = rug::Integer::from(3_215_031_751u64);
+    if *n < small {
+        return Some(&DET_SMALL_WITNESSES);
+    }
+    // 3.317 × 10^24 lower bound for the twelve-base set.
+    let large = rug::Integer::from_str_radix("3317044064679887385961981", 10).unwrap();
+    if *n < large {
+        return Some(&DET_LARGE_WITNESSES);
+    }
+    None
+}
 
-            // Generate random sign bit (0 or 1) and convert to -1 or +1
-            let sign_bit = two.clone().random_below(&mut prime_gen);
-            let sign = rug::Integer::from(&two * &sign_bit - &one); // Convert 0,1 to -1,+1
+/// Primality test preferring the deterministic Miller–Rabin path for candidates
+/// below the proven thresholds and falling back to the probabilistic test above
+/// them. Both paths agree on genuine primes, so the generated output is
+/// unchanged.
+fn is_prime(n: &rug::Integer) -> bool {
+    // Machine-word candidates take the exact, allocation-free deterministic path.
+    if let Some(word) = n.to_u64() {
+        return HashToPrime::is_prime_u64(word);
+    }
 
-            // Apply transformation: candidate = 6 * candidate + sign
-            // Use more efficient in-place operations
-            candidate *= &six;
-            candidate += &sign;
+    match deterministic_witnesses(n) {
+        Some(witnesses) => miller_rabin_deterministic(n, witnesses),
+        // Above every proven deterministic bound, accept on Baillie–PSW, which
+        // has no known pseudoprimes and is cheaper than dozens of MR rounds.
+        None => baillie_psw(n),
+    }
+}
 
-            // Ensure candidate is at least 2
-            if candidate < 2 {
-                count += 1;
-                continue;
+/// Baillie–PSW probable-prime test: a strong Fermat test to base 2 followed by a
+/// strong Lucas test with Selfridge's parameters. A composite must fool both to
+/// be accepted, and no such number is known, so for the sizes here this is
+/// effectively deterministic.
+fn baillie_psw(n: &rug::Integer) -> bool {
+    if *n < 2 {
+        return false;
+    }
+    if n.is_even() {
+        return *n == 2;
+    }
+    // Strong Fermat probable-prime test, base 2.
+    if !miller_rabin_deterministic(n, &[2]) {
+        return false;
+    }
+    strong_lucas_selfridge(n)
+}
+
+/// Strong Lucas probable-prime test using Selfridge's method A for choosing the
+/// discriminant `D`, with `P = 1` and `Q = (1 − D)/4`.
+fn strong_lucas_selfridge(n: &rug::Integer) -> bool {
+    // A perfect square never yields a `D` with Jacobi symbol −1 and is composite.
+    if n.is_perfect_square() {
+        return false;
+    }
+
+    // Search D over 5, -7, 9, -11, ... for the first with Jacobi(D, n) = -1.
+    let mut d = rug::Integer::from(5);
+    let mut sign = 1i32;
+    let d = loop {
+        let candidate = rug::Integer::from(&d * sign);
+        if candidate.jacobi(n) == -1 {
+            break candidate;
+        }
+        d += 2;
+        sign = -sign;
+    };
+
+    let q = rug::Integer::from(rug::Integer::from(1) - &d) / 4;
+    let p = rug::Integer::from(1);
+
+    // n + 1 = dd · 2^s with dd odd.
+    let delta = rug::Integer::from(n + 1);
+    let s = delta.find_one(0).unwrap_or(0);
+    let dd = rug::Integer::from(&delta >> s);
+
+    // Compute U_dd, V_dd and Q^dd mod n.
+    let (u, mut v, mut qk) = lucas_sequence(n, &dd, &p, &q);
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+
+    // Strong test: some V_{dd·2^r} ≡ 0 (mod n) for 0 < r < s.
+    for _ in 1..s {
+        // V_{2k} = V_k^2 - 2·Q^k.
+        v = (rug::Integer::from(&v * &v) - rug::Integer::from(2) * &qk) % n;
+        if v < 0 {
+            v += n;
+        }
+        if v.is_zero() {
+            return true;
+        }
+        qk = rug::Integer::from(&qk * &qk) % n;
+    }
+    false
+}
+
+/// Computes `(U_k, V_k, Q^k) mod n` for the Lucas sequences with parameters
+/// `(P, Q)` by the binary method over the bits of `k`.
+fn lucas_sequence(
+    n: &rug::Integer,
+    k: &rug::Integer,
+    p: &rug::Integer,
+    q: &rug::Integer,
+) -> (rug::Integer, rug::Integer, rug::Integer) {
+    let d = rug::Integer::from(rug::Integer::from(p * p) - rug::Integer::from(4) * q);
+
+    // Start from U_1 = 1, V_1 = P, Q^1 = Q, then fold in each lower bit.
+    let mut u = rug::Integer::from(1);
+    let mut v = p.clone();
+    let mut qk = q.clone();
+
+    let bits = k.significant_bits();
+    for i in (0..bits.saturating_sub(1)).rev() {
+        // Doubling: U_{2m}=U_m·V_m, V_{2m}=V_m^2-2·Q^m, Q^{2m}=(Q^m)^2.
+        u = rug::Integer::from(&u * &v) % n;
+        v = (rug::Integer::from(&v * &v) - rug::Integer::from(2) * &qk) % n;
+        if v < 0 {
+            v += n;
+        }
+        qk = rug::Integer::from(&qk * &qk) % n;
+
+        if k.get_bit(i) {
+            // Step by one: indices m -> m+1.
+            let u_next = half_mod(rug::Integer::from(p * &u) + &v, n);
+            let v_next = half_mod(rug::Integer::from(&d * &u) + rug::Integer::from(p * &v), n);
+            u = u_next;
+            v = v_next;
+            qk = rug::Integer::from(&qk * q) % n;
+        }
+    }
+    (u, v, qk)
+}
+
+/// Returns `value/2 mod n` for odd `n`: `value` is halved directly when even,
+/// otherwise `(value + n)/2`. The result is reduced into `[0, n)`.
+fn half_mod(mut value: rug::Integer, n: &rug::Integer) -> rug::Integer {
+    value %= n;
+    if value < 0 {
+        value += n;
+    }
+    if value.is_odd() {
+        value += n;
+    }
+    value /= 2;
+    value
+}
+
+/// Smallest proven strong-probable-prime witness set for an odd `n < 2^64`.
+///
+/// The tiers are the standard minimal base sets: a single base suffices below
+/// 2047 and the set widens as `n` grows, topping out at the seven-base set of
+/// Jaeschke/Sinclair that is deterministic for the whole `u64` range.
+fn deterministic_witnesses_u64(n: u64) -> &'static [u64] {
+    if n < 2_047 {
+        &[2]
+    } else if n < 1_373_653 {
+        &[2, 3]
+    } else if n < 9_080_191 {
+        &[31, 73]
+    } else if n < 3_215_031_751 {
+        &[2, 3, 5, 7]
+    } else if n < 3_474_749_660_383 {
+        &[2, 3, 5, 7, 11, 13]
+    } else {
+        &[2, 325, 9375, 28178, 450775, 9780504, 1795265022]
+    }
+}
+
+/// `a·b mod m` for `u64` operands, widening through `u128` to avoid overflow.
+fn mulmod_u64(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// `base^exp mod m` by square-and-multiply in `u64`/`u128` arithmetic.
+fn powmod_u64(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1u64 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mulmod_u64(result, base, m);
+        }
+        base = mulmod_u64(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Strong-probable-prime test for an odd `n` over the given word-sized
+/// `witnesses`. Writes `n − 1 = d · 2^s` and, for each base `a`, accepts when
+/// `a^d ≡ 1` or some `a^(d·2^r) ≡ n − 1`, else reports composite.
+fn strong_probable_prime_u64(n: u64, witnesses: &[u64]) -> bool {
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for &a in witnesses {
+        let a = a % n;
+        if a == 0 {
+            continue; // base is a multiple of n; no information
+        }
+        let mut x = powmod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 0..s.saturating_sub(1) {
+            x = mulmod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
             }
+        }
+        return false;
+    }
+    true
+}
 
-            // Test for primality using Miller-Rabin
-            is_prime = candidate.is_probably_prime(MAX_MILLER_RABIN);
-            count += 1;
+/// Deterministic Miller–Rabin over the fixed `witnesses`: writes
+/// `n − 1 = d · 2^s` with `d` odd, then for each base `a` accepts when
+/// `a^d ≡ 1` or some `a^(d·2^r) ≡ n − 1`, else reports composite.
+fn miller_rabin_deterministic(n: &rug::Integer, witnesses: &[u32]) -> bool {
+    if *n < 2 {
+        return false;
+    }
+    if *n < 4 {
+        return true; // 2 and 3
+    }
+    if n.is_even() {
+        return false;
+    }
+
+    let n_minus_1 = rug::Integer::from(n - 1);
+    let s = n_minus_1.find_one(0).unwrap_or(0); // trailing zero bits
+    let d = rug::Integer::from(&n_minus_1 >> s);
+
+    'witness: for &a in witnesses {
+        let a = rug::Integer::from(a);
+        if rug::Integer::from(&a % n).is_zero() {
+            continue; // base is a multiple of n; no information
+        }
+        let mut x = a.pow_mod(&d, n).expect("exponent is non-negative");
+        if x == 1 || x == n_minus_1 {
+            continue;
+        }
+        for _ in 0..s.saturating_sub(1) {
+            x.square_mut();
+            x %= n;
+            if x == n_minus_1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Returns the primes below `bound` via a Sieve of Eratosthenes, memoized by
+/// bound so repeated instances share the same table. Primes are capped to the
+/// `u32` range, which the `1 << 16` default comfortably satisfies.
+fn sieve_primes(bound: u64) -> Arc<Vec<u64>> {
+    let bound = bound.min(u32::MAX as u64);
+    let cache = SIEVE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Ok(guard) = cache.lock() {
+        if let Some(primes) = guard.get(&bound) {
+            return primes.clone();
         }
+    }
 
-        if count == MAX_ITER {
-            return Err(format!(
-                "Prime not found within {} iterations for practical_bits={}",
-                MAX_ITER, practical_bits
-            ));
+    let n = bound as usize;
+    let mut is_composite = vec![false; n.max(2)];
+    let mut primes = Vec::new();
+    for i in 2..n {
+        if !is_composite[i] {
+            primes.push(i as u64);
+            let mut j = i * i;
+            while j < n {
+                is_composite[j] = true;
+                j += i;
+            }
         }
+    }
+    let primes = Arc::new(primes);
 
-        Ok(candidate)
+    if let Ok(mut guard) = cache.lock() {
+        guard.insert(bound, primes.clone());
     }
+    primes
+}
+
+/// Classic segmented Sieve of Eratosthenes over the half-open window
+/// `[low, high)`: returns a bool table where index `i` marks whether `low + i`
+/// is composite. Multiples of each base prime below `sqrt(high)` are struck
+/// within the segment, which is the reusable primitive behind batch candidate
+/// screening.
+pub fn segmented_sieve(low: u64, high: u64) -> Vec<bool> {
+    if high <= low {
+        return Vec::new();
+    }
+    let len = (high - low) as usize;
+    let mut composite = vec![false; len];
+    let limit = (high as f64).sqrt() as u64 + 1;
+    for &p in sieve_primes(limit + 1).iter() {
+        if p * p >= high {
+            break;
+        }
+        // First multiple of p within [low, high), never p itself.
+        let mut start = low.div_ceil(p) * p;
+        if start < p * p {
+            start = p * p;
+        }
+        let mut m = start;
+        while m < high {
+            if m >= low {
+                composite[(m - low) as usize] = true;
+            }
+            m += p;
+        }
+    }
+    composite
+}
+
+/// Computes Keccak-256 over the concatenation of `parts` into `out`.
+fn keccak256_into(parts: &[&[u8]], out: &mut [u8; 32]) {
+    use tiny_keccak::{Hasher, Keccak};
+    let mut hasher = Keccak::v256();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize(out);
 }
 
 /// Compute precision bound using Lambert W function approximation.
@@ -493,6 +1202,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mod_30_wheel_residue_class() {
+        // Every emitted prime (all far above 5) must land in one of the eight
+        // residue classes coprime to 30.
+        let h2p = HashToPrime::new(128);
+        for seed in [1u32, 42, 1337, 12345, 987654, 555555] {
+            let prime = h2p.hash_to_prime(&rug::Integer::from(seed)).unwrap();
+            let residue = rug::Integer::from(&prime % WHEEL_MODULUS).to_u32().unwrap();
+            assert!(
+                WHEEL_RESIDUES.contains(&residue),
+                "prime {} has residue {} mod 30, outside the wheel",
+                prime,
+                residue
+            );
+        }
+    }
+
     #[test]
     fn test_integer_bytes_conversion() {
         // Test integer to bytes conversion (used internally for seeding)
@@ -541,6 +1267,190 @@ mod tests {
         assert!(rem_val == 1 || rem_val == 5);
     }
 
+    #[test]
+    fn test_keccak_backend_produces_prime() {
+        let h2p = HashToPrime::with_backend(128, HashBackend::Keccak256);
+        assert_eq!(h2p.backend(), HashBackend::Keccak256);
+
+        let input = rug::Integer::from(42u32);
+        let prime = h2p.hash_to_prime(&input).unwrap();
+
+        use rug::integer::IsPrime;
+        assert_eq!(prime.is_probably_prime(30), IsPrime::Yes);
+        assert!(prime > 1);
+    }
+
+    #[test]
+    fn test_keccak_backend_deterministic() {
+        let h2p1 = HashToPrime::with_backend(128, HashBackend::Keccak256);
+        let h2p2 = HashToPrime::with_backend(128, HashBackend::Keccak256);
+        let input = rug::Integer::from(1337u32);
+
+        assert_eq!(
+            h2p1.hash_to_prime(&input).unwrap(),
+            h2p2.hash_to_prime(&input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_keccak_prefilter_still_yields_primes() {
+        // The incremental residue prefilter must never reject a true prime, so
+        // every seed still resolves to a probable prime.
+        use rug::integer::IsPrime;
+        let h2p = HashToPrime::with_backend(64, HashBackend::Keccak256);
+        for seed in [1u32, 42, 1337, 9999, 123456] {
+            let prime = h2p.hash_to_prime(&rug::Integer::from(seed)).unwrap();
+            assert_eq!(prime.is_probably_prime(30), IsPrime::Yes, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_default_backend_is_keccak256() {
+        let h2p = HashToPrime::new(128);
+        assert_eq!(h2p.backend(), HashBackend::Keccak256);
+    }
+
+    #[test]
+    fn test_deterministic_miller_rabin_matches_known_values() {
+        for n in [2u64, 3, 5, 7, 97, 7919, 104729] {
+            assert!(is_prime(&rug::Integer::from(n)), "{n} is prime");
+        }
+        for n in [0u64, 1, 4, 9, 21, 100, 7917] {
+            assert!(!is_prime(&rug::Integer::from(n)), "{n} is composite");
+        }
+    }
+
+    #[test]
+    fn test_baillie_psw_large_values() {
+        // M89 = 2^89 - 1 is prime; its neighbour is composite. Both exceed the
+        // deterministic bound, so they exercise the BPSW path.
+        let m89 = rug::Integer::from_str_radix("618970019642690137449562111", 10).unwrap();
+        assert!(baillie_psw(&m89), "2^89-1 is prime");
+        let composite = rug::Integer::from(&m89 + 2);
+        assert!(!baillie_psw(&composite), "2^89+1 is composite");
+    }
+
+    #[test]
+    fn test_mr_rounds_knob_accepts_primes() {
+        // Extra MR rounds must not reject genuine primes.
+        let h2p = HashToPrime::with_mr_rounds(128, 16);
+        let prime = h2p.hash_to_prime(&rug::Integer::from(42u32)).unwrap();
+        use rug::integer::IsPrime;
+        assert_eq!(prime.is_probably_prime(30), IsPrime::Yes);
+    }
+
+    #[test]
+    fn test_is_prime_u64_known_values() {
+        for n in [2u64, 3, 5, 7, 97, 7919, 104729, 2147483647, 4294967291] {
+            assert!(HashToPrime::is_prime_u64(n), "{n} is prime");
+        }
+        for n in [0u64, 1, 4, 9, 21, 100, 7917, 2147483649, 3825123056546413051] {
+            assert!(!HashToPrime::is_prime_u64(n), "{n} is composite");
+        }
+    }
+
+    #[test]
+    fn test_is_prime_u64_matches_probable_prime() {
+        use rug::integer::IsPrime;
+        for n in [999983u64, 1000000, 67280421310721, 600851475143] {
+            let probable = rug::Integer::from(n).is_probably_prime(30) == IsPrime::Yes;
+            assert_eq!(HashToPrime::is_prime_u64(n), probable, "disagreement on {n}");
+        }
+    }
+
+    #[test]
+    fn test_hash_to_prime_certified_flag() {
+        // 128-bit output is well above the deterministic bound -> not certified.
+        let (prime, certified) = HashToPrime::new(128)
+            .hash_to_prime_certified(&rug::Integer::from(42u32))
+            .unwrap();
+        assert!(prime > 1);
+        assert!(!certified);
+
+        // A tiny lambda yields a small prime that the twelve-base set certifies.
+        let (small_prime, small_certified) = HashToPrime::new(1)
+            .hash_to_prime_certified(&rug::Integer::from(7u32))
+            .unwrap();
+        assert_eq!(
+            small_certified,
+            deterministic_witnesses(&small_prime).is_some()
+        );
+    }
+
+    #[test]
+    fn test_prime_bits_controls_realized_length() {
+        // The realized prime must match the requested bit-length within one bit
+        // and scale well past the former 32-bit clamp.
+        for bits in [64u32, 128, 256, 384] {
+            let h2p = HashToPrime::with_prime_bits(128, bits);
+            let prime = h2p.hash_to_prime(&rug::Integer::from(12345u32)).unwrap();
+            let realized = prime.significant_bits();
+            assert!(
+                realized.abs_diff(bits) <= 1,
+                "requested {bits} bits, got {realized}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_prime_bits_is_two_lambda() {
+        assert_eq!(HashToPrime::new(128).prime_bits(), 256);
+    }
+
+    #[test]
+    fn test_sieve_limit_matches_default() {
+        // Tuning the sieve limit must not change the generated prime.
+        let input = rug::Integer::from(192837465u64);
+        let default = HashToPrime::new(128).hash_to_prime(&input).unwrap();
+        let tiny = HashToPrime::with_sieve_limit(128, 10)
+            .hash_to_prime(&input)
+            .unwrap();
+        assert_eq!(default, tiny);
+    }
+
+    #[test]
+    fn test_sieve_prefilter_matches_default() {
+        // A custom sieve bound must not change the generated prime.
+        let input = rug::Integer::from(987654321u64);
+        let default = HashToPrime::new(128).hash_to_prime(&input).unwrap();
+        let small = HashToPrime::with_sieve_bound(128, 256)
+            .hash_to_prime(&input)
+            .unwrap();
+        let large = HashToPrime::with_sieve_bound(128, 1 << 18)
+            .hash_to_prime(&input)
+            .unwrap();
+        assert_eq!(default, small);
+        assert_eq!(default, large);
+    }
+
+    #[test]
+    fn test_hash_to_prime_batch_matches_single() {
+        let h2p = HashToPrime::new(64);
+        let inputs: Vec<rug::Integer> =
+            [11u32, 222, 3333, 44444, 555555].iter().map(rug::Integer::from).collect();
+
+        let batch = h2p.hash_to_prime_batch(&inputs);
+        for (input, batched) in inputs.iter().zip(batch) {
+            assert_eq!(batched.unwrap(), h2p.hash_to_prime(input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_segmented_sieve_marks_composites() {
+        // Window [10, 20): primes are 11, 13, 17, 19.
+        let composite = segmented_sieve(10, 20);
+        let primes: Vec<u64> = (10u64..20)
+            .filter(|n| !composite[(n - 10) as usize])
+            .collect();
+        assert_eq!(primes, vec![11, 13, 17, 19]);
+    }
+
+    #[test]
+    fn test_sieve_primes_are_correct() {
+        let primes = sieve_primes(30);
+        assert_eq!(*primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
     #[test]
     fn test_caching_performance() {
         use std::time::Instant;
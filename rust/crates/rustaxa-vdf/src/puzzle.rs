@@ -1,9 +1,134 @@
+/// Feature-gated fixed-width limb backend for the Montgomery squaring chains.
+#[cfg(feature = "fixed-bigint")]
+pub mod fixed;
+
 // Rivest-Shamir-Wagner puzzle
 pub struct RswPuzzle {
     time_bits: u32,           // log₂(T) - determines T = 2^time_bits
     base: rug::Integer,       // x - the base value to be time-locked
     modulus: rug::Integer,    // N - the RSA modulus (p × q)
     iterations: rug::Integer, // T - number of sequential operations (2^time_bits)
+    montgomery: Option<MontgomeryCtx>, // REDC constants, present only for odd N
+    trapdoor: Option<(rug::Integer, rug::Integer)>, // (p, q) if the factorization is known
+}
+
+/// Precomputed Montgomery-reduction constants for an odd modulus `N`.
+///
+/// The sequential squaring `x -> x^2 mod N` is the hot path of any RSW/VDF
+/// computation, and a `mul`-then-`div` per step wastes most of its time in
+/// division. Working in Montgomery form lets the whole `T`-squaring loop run
+/// with REDC (multiply, reduce by `R` via shifts/adds) and no trial division;
+/// the base is converted in once and the result converted back out once.
+pub struct MontgomeryCtx {
+    shift: u32,          // bit width of R = 2^(limbs*64)
+    r2: rug::Integer,    // R^2 mod N, for conversion into Montgomery form
+    n_prime: rug::Integer, // -N^{-1} mod R
+    r_mask: rug::Integer, // R - 1, for the `mod R` step
+}
+
+impl MontgomeryCtx {
+    /// Builds the REDC constants for an odd modulus, or returns `None` for even
+    /// moduli (which fall back to the remainder-based squaring path).
+    pub fn new(modulus: &rug::Integer) -> Option<Self> {
+        if modulus.is_even() || *modulus <= 1 {
+            return None;
+        }
+        // R is the next power of 2^64 above N.
+        let limbs = modulus.significant_bits().div_ceil(64).max(1);
+        let shift = limbs * 64;
+        let r = rug::Integer::from(1) << shift;
+        let r2 = rug::Integer::from(&r * &r) % modulus;
+        // n' = -N^{-1} mod R.
+        let n_inv = modulus
+            .clone()
+            .invert(&r)
+            .expect("odd modulus is invertible mod a power of two");
+        let n_prime = (&r - n_inv) % &r;
+        let r_mask = rug::Integer::from(&r - 1);
+        Some(MontgomeryCtx {
+            shift,
+            r2,
+            n_prime,
+            r_mask,
+        })
+    }
+
+    /// Montgomery reduction: given `t < N·R`, returns `t·R^{-1} mod N`.
+    fn redc(&self, t: rug::Integer, modulus: &rug::Integer) -> rug::Integer {
+        let m = rug::Integer::from(&t & &self.r_mask);
+        let m = (m * &self.n_prime) & &self.r_mask;
+        let mut result = (t + m * modulus) >> self.shift;
+        if result >= *modulus {
+            result -= modulus;
+        }
+        result
+    }
+
+    /// Converts `a` into Montgomery form `aR mod N`.
+    pub fn to_form(&self, a: &rug::Integer, modulus: &rug::Integer) -> rug::Integer {
+        self.redc(rug::Integer::from(a * &self.r2), modulus)
+    }
+
+    /// Converts `a_mont = aR mod N` back to `a mod N`.
+    pub fn from_form(&self, a_mont: rug::Integer, modulus: &rug::Integer) -> rug::Integer {
+        self.redc(a_mont, modulus)
+    }
+
+    /// Montgomery multiplication: `REDC(x·y)`.
+    pub fn mul(&self, x: &rug::Integer, y: &rug::Integer, modulus: &rug::Integer) -> rug::Integer {
+        self.redc(rug::Integer::from(x * y), modulus)
+    }
+
+    /// The Montgomery representation of `1`, namely `R mod N` — the identity for
+    /// [`mul`](Self::mul) and the starting accumulator for a squaring chain.
+    pub fn one(&self, modulus: &rug::Integer) -> rug::Integer {
+        (rug::Integer::from(1) << self.shift) % modulus
+    }
+
+    /// Modular exponentiation `base^exp mod N` carried out entirely in
+    /// Montgomery form: the base is converted in once, the left-to-right
+    /// square-and-multiply runs on REDC multiplications (no per-step division),
+    /// and the accumulator is converted back out once. This is the quotient
+    /// exponentiation on the Wesolowski prover's hot path.
+    pub fn pow_mod(
+        &self,
+        base: &rug::Integer,
+        exp: &rug::Integer,
+        modulus: &rug::Integer,
+    ) -> rug::Integer {
+        // `R mod N` is the Montgomery representation of 1, the identity.
+        let one = (rug::Integer::from(1) << self.shift) % modulus;
+        let base_form = self.to_form(base, modulus);
+        let mut acc = one;
+        for bit in (0..exp.significant_bits()).rev() {
+            acc = self.mul(&acc, &acc, modulus);
+            if exp.get_bit(bit) {
+                acc = self.mul(&acc, &base_form, modulus);
+            }
+        }
+        self.from_form(acc, modulus)
+    }
+}
+
+/// Selects which proof system [`RswPuzzle::prove_with`] uses.
+///
+/// [`ProofSystem::Wesolowski`] yields a single-element succinct proof; the
+/// interactive-style [`ProofSystem::Pietrzak`] yields a larger proof (a
+/// logarithmic list of midpoints) but a cheaper prover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofSystem {
+    Wesolowski,
+    Pietrzak,
+}
+
+/// A proof that `y = x^(2^T) mod N` was computed correctly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Proof {
+    /// Succinct Wesolowski proof: a single group element `pi`.
+    Wesolowski { pi: rug::Integer },
+    /// Pietrzak proof: the list of midpoints `mu` collected during the
+    /// logarithmic halving.
+    Pietrzak { mus: Vec<rug::Integer> },
 }
 
 impl RswPuzzle {
@@ -12,15 +137,118 @@ impl RswPuzzle {
         let modulus = rug::Integer::from_digits(modulus, rug::integer::Order::MsfBe);
         let iterations = rug::Integer::from(1) << time_bits;
         let base = base % &modulus;
+        let montgomery = MontgomeryCtx::new(&modulus);
 
         RswPuzzle {
             time_bits,
             base,
             modulus,
             iterations,
+            montgomery,
+            trapdoor: None,
         }
     }
 
+    /// Constructs a puzzle from the known prime factors `p` and `q` of the
+    /// modulus, retaining them as a trapdoor so the creator can
+    /// [`solve_with_trapdoor`](Self::solve_with_trapdoor) in `O(log T)` instead
+    /// of `O(T)` squarings.
+    pub fn with_trapdoor(time_bits: u32, input: &[u8], p: &[u8], q: &[u8]) -> Self {
+        let p = rug::Integer::from_digits(p, rug::integer::Order::MsfBe);
+        let q = rug::Integer::from_digits(q, rug::integer::Order::MsfBe);
+        let modulus = rug::Integer::from(&p * &q);
+
+        let base = rug::Integer::from_digits(input, rug::integer::Order::MsfBe);
+        let base = base % &modulus;
+        let iterations = rug::Integer::from(1) << time_bits;
+        let montgomery = MontgomeryCtx::new(&modulus);
+
+        RswPuzzle {
+            time_bits,
+            base,
+            modulus,
+            iterations,
+            montgomery,
+            trapdoor: Some((p, q)),
+        }
+    }
+
+    /// Generates a cryptographically sound puzzle with a random `bits`-bit
+    /// modulus `N = p·q`, sampling both primes from the supplied `RngCore`.
+    ///
+    /// Each prime is `bits/2` bits wide and is validated with trial division by
+    /// small primes (a fast reject) followed by several rounds of Miller–Rabin.
+    /// When `safe` is set, each factor is a *safe* prime (`p = 2p'+1` with `p'`
+    /// also prime) to harden against small-order subgroup attacks. Returns the
+    /// puzzle — carrying the factors as its trapdoor — together with `p` and `q`.
+    ///
+    /// A seedable PRNG (e.g. an xoshiro-style generator) yields reproducible
+    /// moduli for benches.
+    pub fn generate<R: rand::RngCore>(
+        time_bits: u32,
+        bits: u32,
+        safe: bool,
+        rng: &mut R,
+    ) -> (RswPuzzle, rug::Integer, rug::Integer) {
+        let half = (bits / 2).max(2);
+        let p = random_prime(half, safe, rng);
+        let q = loop {
+            let candidate = random_prime(half, safe, rng);
+            if candidate != p {
+                break candidate;
+            }
+        };
+
+        let p_bytes = p.to_digits::<u8>(rug::integer::Order::MsfBe);
+        let q_bytes = q.to_digits::<u8>(rug::integer::Order::MsfBe);
+
+        // A random base in [0, N) time-locked by the puzzle.
+        let modulus = rug::Integer::from(&p * &q);
+        let base = random_integer(bits, rng) % &modulus;
+        let base_bytes = base.to_digits::<u8>(rug::integer::Order::MsfBe);
+
+        let puzzle = RswPuzzle::with_trapdoor(time_bits, &base_bytes, &p_bytes, &q_bytes);
+        (puzzle, p, q)
+    }
+
+    /// Fast-solves the puzzle using the trapdoor factorization via Euler's
+    /// theorem: with `phi = (p-1)(q-1)`, reduce the exponent `e = 2^T mod phi`
+    /// and return `x^e mod N` by ordinary fast modular exponentiation.
+    ///
+    /// Returns `None` if no trapdoor is present. When `gcd(x, N) != 1` Euler's
+    /// theorem does not apply directly, so the slow [`evaluate`](Self::evaluate)
+    /// path is used to keep the output identical.
+    pub fn solve_with_trapdoor(&self) -> Option<rug::Integer> {
+        let (p, q) = self.trapdoor.as_ref()?;
+
+        // gcd(x, N) edge case: fall back to the direct computation.
+        if lehmer_gcd(&self.base, &self.modulus) != 1 {
+            return Some(self.evaluate());
+        }
+
+        #[allow(unused_mut)]
+        let mut phi = rug::Integer::from(p - 1) * rug::Integer::from(q - 1);
+        #[allow(unused_mut)]
+        let mut e = rug::Integer::from(2)
+            .pow_mod(&self.iterations, &phi)
+            .expect("phi is positive");
+        let result = self
+            .base
+            .clone()
+            .pow_mod(&e, &self.modulus)
+            .expect("exponent is non-negative");
+
+        // `phi` and the reduced exponent `e` both leak the factorization; scrub
+        // them before they leave scope when the `zeroize` feature is enabled.
+        #[cfg(feature = "zeroize")]
+        {
+            zeroize_int(&mut phi);
+            zeroize_int(&mut e);
+        }
+
+        Some(result)
+    }
+
     pub fn time_bits(&self) -> u32 {
         self.time_bits
     }
@@ -33,9 +261,413 @@ impl RswPuzzle {
         &self.modulus
     }
 
+    /// The precomputed Montgomery-reduction constants for this puzzle's modulus,
+    /// or `None` for an even/degenerate `N` that falls back to remainder-based
+    /// squaring.
+    pub fn montgomery(&self) -> Option<&MontgomeryCtx> {
+        self.montgomery.as_ref()
+    }
+
     pub fn iterations(&self) -> &rug::Integer {
         &self.iterations
     }
+
+    /// Evaluates the delay function `y = x^(2^T) mod N` by `T` sequential
+    /// modular squarings, using the same big-integer backend as the rest of the
+    /// puzzle.
+    pub fn evaluate(&self) -> rug::Integer {
+        let t = self.iterations_u64();
+
+        // GMP-free path: an odd modulus that fits one of the fixed widths runs
+        // the squaring chain entirely on the stack.
+        #[cfg(feature = "fixed-bigint")]
+        if let Some(y) = self.evaluate_fixed(t) {
+            return y;
+        }
+
+        // Fast path: Montgomery form removes all trial division from the loop.
+        if let Some(mont) = &self.montgomery {
+            let mut y = mont.to_form(&self.base, &self.modulus);
+            for _ in 0..t {
+                y = mont.mul(&y, &y, &self.modulus);
+            }
+            return mont.from_form(y, &self.modulus);
+        }
+
+        // Fallback for even moduli: plain squaring with remainder.
+        let mut y = self.base.clone();
+        for _ in 0..t {
+            y.square_mut();
+            y %= &self.modulus;
+        }
+        y
+    }
+
+    /// Evaluates the delay function on the fixed-width limb backend, selected
+    /// when the modulus is odd and fits one of the supported widths (≤ 4096
+    /// bits). Returns `None` otherwise so [`evaluate`](Self::evaluate) falls
+    /// back to the `rug` path.
+    #[cfg(feature = "fixed-bigint")]
+    fn evaluate_fixed(&self, t: u64) -> Option<rug::Integer> {
+        use fixed::{FixedMontgomery, FixedUint};
+
+        if self.modulus.is_even() {
+            return None;
+        }
+        let bits = self.modulus.significant_bits();
+        // `check_interval = t` polls the closure once, at the last step, and it
+        // never stops the chain; the puzzle-level evaluation is not cancellable.
+        if bits <= 2048 {
+            let n = FixedUint::<32>::from_rug(&self.modulus);
+            let mont = FixedMontgomery::new(&n)?;
+            let x = FixedUint::<32>::from_rug(&self.base);
+            mont.square_chain(&x, t, t.max(1), |_| false)
+                .map(|y| y.to_rug())
+        } else if bits <= 4096 {
+            let n = FixedUint::<64>::from_rug(&self.modulus);
+            let mont = FixedMontgomery::new(&n)?;
+            let x = FixedUint::<64>::from_rug(&self.base);
+            mont.square_chain(&x, t, t.max(1), |_| false)
+                .map(|y| y.to_rug())
+        } else {
+            None
+        }
+    }
+
+    /// Produces a proof for `y = evaluate()` using the default Wesolowski
+    /// system.
+    pub fn prove(&self) -> Proof {
+        self.prove_with(ProofSystem::Wesolowski)
+    }
+
+    /// Verifies a [`Proof`] against the claimed output `y`, matching
+    /// [`rust/libs/vdf`]'s `WesolowskiVdf::verify(y, pi)`: the verifier checks
+    /// the proof against a `y` the caller supplies rather than recomputing it
+    /// via [`evaluate`](Self::evaluate). Recomputing would mean every
+    /// "verification" redoes the prover's full `O(T)` work itself — for
+    /// [`ProofSystem::Pietrzak`] in particular that defeats the whole point of
+    /// a proof system whose verifier is only `O(log T)` group operations.
+    pub fn verify(&self, y: &rug::Integer, proof: &Proof) -> bool {
+        match proof {
+            Proof::Wesolowski { pi } => self.verify_wesolowski(y, pi),
+            Proof::Pietrzak { mus } => self.verify_pietrzak(y, mus),
+        }
+    }
+
+    /// Produces a proof using the chosen [`ProofSystem`].
+    pub fn prove_with(&self, system: ProofSystem) -> Proof {
+        match system {
+            ProofSystem::Wesolowski => self.prove_wesolowski(),
+            ProofSystem::Pietrzak => self.prove_pietrzak(),
+        }
+    }
+
+    /// Wesolowski prover: `l = hash_to_prime(x, y, T, N)`, `q = floor(2^T / l)`,
+    /// `pi = x^q mod N`. Verification re-checks `pi^l · x^r == y` with a single
+    /// short exponentiation instead of `T` squarings.
+    fn prove_wesolowski(&self) -> Proof {
+        let y = self.evaluate();
+        let l = self.challenge_prime(&y);
+
+        let two_pow_t = self.two_pow_t();
+        let q = rug::Integer::from(&two_pow_t / &l);
+        // Odd N (always true for RSA moduli) takes the division-free Montgomery
+        // exponentiation; even moduli keep the generic backend path.
+        let pi = match &self.montgomery {
+            Some(mont) => mont.pow_mod(&self.base, &q, &self.modulus),
+            None => self
+                .base
+                .clone()
+                .pow_mod(&q, &self.modulus)
+                .expect("exponent is non-negative"),
+        };
+
+        Proof::Wesolowski { pi }
+    }
+
+    fn verify_wesolowski(&self, y: &rug::Integer, pi: &rug::Integer) -> bool {
+        let l = self.challenge_prime(y);
+
+        let two_pow_t = self.two_pow_t();
+        let r = rug::Integer::from(&two_pow_t % &l);
+
+        let pi_l = match pi.clone().pow_mod(&l, &self.modulus) {
+            Ok(v) => v,
+            Err(_) => return false,
+        };
+        let x_r = match self.base.clone().pow_mod(&r, &self.modulus) {
+            Ok(v) => v,
+            Err(_) => return false,
+        };
+
+        let mut lhs = pi_l;
+        lhs *= x_r;
+        lhs %= &self.modulus;
+        &lhs == y
+    }
+
+    /// Pietrzak prover: recursively halve the exponent. At each round with claim
+    /// `(x, y, T)` compute `mu = x^(2^(T/2)) mod N`, derive `r = Hash(x, y, mu)`,
+    /// and recurse on the folded instance `(x^r·mu, mu^r·y, T/2)`, collecting
+    /// each `mu`.
+    fn prove_pietrzak(&self) -> Proof {
+        let modulus = &self.modulus;
+        let mut x = self.base.clone();
+        let mut y = self.evaluate();
+        let mut t = self.iterations_u64();
+        let mut mus = Vec::new();
+
+        while t > 1 {
+            // Odd-T edge case: carry one extra squaring so the exponent halves
+            // cleanly (x^(2^t) = y  =>  x^(2^(t+1)) = y^2).
+            if t % 2 == 1 {
+                y.square_mut();
+                y %= modulus;
+                t += 1;
+            }
+            let half = t / 2;
+
+            let mu = pow_2exp(&x, half, modulus);
+            let r = fold_challenge(&x, &y, &mu);
+
+            x = combine(&x, &r, &mu, modulus); // x^r · mu
+            y = combine(&mu, &r, &y, modulus); // mu^r · y
+            mus.push(mu);
+            t = half;
+        }
+
+        Proof::Pietrzak { mus }
+    }
+
+    /// Pietrzak verifier: replay the folding from the list of `mu` values
+    /// against the claimed `y` and check the final relation `y == x^2 (mod N)`
+    /// with a single squaring. This only does `O(log T)` group operations
+    /// total (one per fold, no full squaring chain), which is the point of
+    /// the proof system; it trusts the caller's `y` rather than recomputing
+    /// it, same as [`verify_wesolowski`](Self::verify_wesolowski).
+    fn verify_pietrzak(&self, y: &rug::Integer, mus: &[rug::Integer]) -> bool {
+        let modulus = &self.modulus;
+        let mut x = self.base.clone();
+        let mut y = y.clone();
+        let mut t = self.iterations_u64();
+        let mut idx = 0usize;
+
+        while t > 1 {
+            if t % 2 == 1 {
+                y.square_mut();
+                y %= modulus;
+                t += 1;
+            }
+            let half = t / 2;
+
+            let mu = match mus.get(idx) {
+                Some(mu) => mu,
+                None => return false, // proof too short
+            };
+            idx += 1;
+
+            let r = fold_challenge(&x, &y, mu);
+            x = combine(&x, &r, mu, modulus);
+            y = combine(mu, &r, &y, modulus);
+            t = half;
+        }
+
+        if idx != mus.len() {
+            return false; // proof too long
+        }
+
+        // Final relation: y == x^(2^1) == x^2.
+        let mut check = x;
+        check.square_mut();
+        check %= modulus;
+        check == y
+    }
+
+    /// Returns `T` as a `u64`. `T = 2^time_bits`, so this fits for any
+    /// practical `time_bits` (< 64).
+    fn iterations_u64(&self) -> u64 {
+        self.iterations
+            .to_u64()
+            .expect("iterations too large for direct evaluation")
+    }
+
+    /// The delay exponent `2^T` as a big integer, where `T = iterations_u64()`
+    /// is the squaring count. The shift width is range-checked rather than cast
+    /// with `as u32`, which would silently truncate `T ≥ 2^32` to `1 << 0` and
+    /// yield a wrong exponent; such a delay is infeasible, so a loud panic is
+    /// the right failure.
+    fn two_pow_t(&self) -> rug::Integer {
+        let shift = u32::try_from(self.iterations_u64())
+            .expect("delay exponent 2^T too large to materialize");
+        rug::Integer::from(1) << shift
+    }
+
+    /// Derives the Fiat–Shamir challenge prime `l = hash_to_prime(x, y, T, N)`:
+    /// hash the serialized tuple, interpret the digest as an integer and advance
+    /// to the next probable prime.
+    fn challenge_prime(&self, y: &rug::Integer) -> rug::Integer {
+        use rug::integer::IsPrime;
+        use tiny_keccak::{Hasher, Keccak};
+
+        let mut hasher = Keccak::v256();
+        for value in [&self.base, y, &self.iterations, &self.modulus] {
+            let bytes = value.to_digits::<u8>(rug::integer::Order::MsfBe);
+            hasher.update(&(bytes.len() as u64).to_be_bytes());
+            hasher.update(&bytes);
+        }
+        let mut digest = [0u8; 32];
+        hasher.finalize(&mut digest);
+
+        let mut candidate = rug::Integer::from_digits(&digest, rug::integer::Order::MsfBe);
+        if candidate.is_even() {
+            candidate += 1;
+        }
+        let two = rug::Integer::from(2);
+        while candidate.is_probably_prime(30) != IsPrime::Yes {
+            candidate += &two;
+        }
+        candidate
+    }
+}
+
+/// Best-effort in-place scrub of a secret [`rug::Integer`].
+///
+/// `rug::Integer` owns its GMP limb allocation, so simply assigning zero would
+/// leave the old magnitude in the backing buffer. Instead the magnitude is first
+/// overwritten (the value is forced to an all-ones mask of the same width and
+/// then cleared, touching every limb) before the value is reset to zero.
+#[cfg(feature = "zeroize")]
+fn zeroize_int(x: &mut rug::Integer) {
+    use rug::Assign;
+    let bits = x.significant_bits();
+    if bits > 0 {
+        let mask = (rug::Integer::from(1) << bits) - 1;
+        *x |= &mask; // write ones across the limbs
+        *x ^= &mask; // clear them again, overwriting the original magnitude
+    }
+    x.assign(0);
+}
+
+/// Scrubs the trapdoor factorization and the time-locked base when the puzzle is
+/// dropped, so the secret primes do not linger in freed memory.
+#[cfg(feature = "zeroize")]
+impl Drop for RswPuzzle {
+    fn drop(&mut self) {
+        if let Some((p, q)) = self.trapdoor.as_mut() {
+            zeroize_int(p);
+            zeroize_int(q);
+        }
+        zeroize_int(&mut self.base);
+    }
+}
+
+/// Small primes used for fast trial-division rejection before Miller–Rabin.
+const SMALL_PRIMES: [u32; 15] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// Miller–Rabin rounds for probable-prime testing.
+const MILLER_RABIN_ROUNDS: u32 = 40;
+
+/// Draws a random `bits`-bit integer with the top bit set from `rng`.
+fn random_integer<R: rand::RngCore>(bits: u32, rng: &mut R) -> rug::Integer {
+    let byte_len = bits.div_ceil(8) as usize;
+    let mut bytes = vec![0u8; byte_len];
+    rng.fill_bytes(&mut bytes);
+    // Force the high bit so the value really is `bits` wide.
+    bytes[0] |= 0x80;
+    rug::Integer::from_digits(&bytes, rug::integer::Order::MsfBe)
+}
+
+/// Samples a random `bits`-bit probable prime from `rng`. When `safe` is set the
+/// returned prime `p` satisfies `p = 2p'+1` with `p'` also prime.
+fn random_prime<R: rand::RngCore>(bits: u32, safe: bool, rng: &mut R) -> rug::Integer {
+    use rug::integer::IsPrime;
+
+    loop {
+        // Candidate prime' (or the prime itself when not enforcing safe primes).
+        let mut candidate = random_integer(if safe { bits - 1 } else { bits }, rng);
+        candidate |= 1; // odd
+
+        if !passes_trial_division(&candidate) {
+            continue;
+        }
+        if candidate.is_probably_prime(MILLER_RABIN_ROUNDS) == IsPrime::No {
+            continue;
+        }
+
+        if !safe {
+            return candidate;
+        }
+
+        // Safe prime: p = 2p' + 1 must also be prime.
+        let p = rug::Integer::from(&candidate * 2) + 1;
+        if passes_trial_division(&p)
+            && p.is_probably_prime(MILLER_RABIN_ROUNDS) != IsPrime::No
+        {
+            return p;
+        }
+    }
+}
+
+/// Fast composite reject: divisible by a small prime (and larger than it).
+fn passes_trial_division(n: &rug::Integer) -> bool {
+    for &p in SMALL_PRIMES.iter() {
+        if n.is_divisible_u(p) && *n != p {
+            return false;
+        }
+    }
+    true
+}
+
+/// Greatest common divisor of `a` and `b`. Delegates to the backend's
+/// Lehmer-style gcd over limb matrices, which is the efficient choice for the
+/// large operands involved here.
+fn lehmer_gcd(a: &rug::Integer, b: &rug::Integer) -> rug::Integer {
+    a.clone().gcd(b)
+}
+
+/// Computes `base^(2^exp) mod modulus` by `exp` sequential squarings — the
+/// shared primitive behind a Pietrzak round's midpoint.
+fn pow_2exp(base: &rug::Integer, exp: u64, modulus: &rug::Integer) -> rug::Integer {
+    let mut acc = base.clone();
+    for _ in 0..exp {
+        acc.square_mut();
+        acc %= modulus;
+    }
+    acc
+}
+
+/// Fiat–Shamir challenge `r = Hash(x, y, mu)` for a Pietrzak round, reduced to a
+/// λ-bit scalar.
+fn fold_challenge(x: &rug::Integer, y: &rug::Integer, mu: &rug::Integer) -> rug::Integer {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let mut hasher = Keccak::v256();
+    for value in [x, y, mu] {
+        let bytes = value.to_digits::<u8>(rug::integer::Order::MsfBe);
+        hasher.update(&(bytes.len() as u64).to_be_bytes());
+        hasher.update(&bytes);
+    }
+    let mut digest = [0u8; 32];
+    hasher.finalize(&mut digest);
+    // Use the low 128 bits as the challenge scalar.
+    rug::Integer::from_digits(&digest[16..], rug::integer::Order::MsfBe)
+}
+
+/// Computes `a^r · b mod modulus`, the folding step shared by both sides of a
+/// Pietrzak round.
+fn combine(
+    a: &rug::Integer,
+    r: &rug::Integer,
+    b: &rug::Integer,
+    modulus: &rug::Integer,
+) -> rug::Integer {
+    let a_r = a
+        .clone()
+        .pow_mod(r, modulus)
+        .expect("challenge is non-negative");
+    let mut out = a_r * b;
+    out %= modulus;
+    out
 }
 
 #[cfg(test)]
@@ -69,4 +701,171 @@ mod tests {
         assert_eq!(puzzle.modulus(), &expected_modulus);
         assert_eq!(puzzle.iterations(), &expected_iterations);
     }
+
+    #[test]
+    fn test_evaluate_matches_manual_squaring() {
+        // x = 3, N = 257, T = 2^3 = 8 squarings.
+        let puzzle = RswPuzzle::new(3, &[0x03], &[0x01, 0x01]);
+        let mut expected = rug::Integer::from(3);
+        for _ in 0..8 {
+            expected.square_mut();
+            expected %= &rug::Integer::from(257);
+        }
+        assert_eq!(puzzle.evaluate(), expected);
+    }
+
+    #[test]
+    fn test_montgomery_matches_plain_squaring() {
+        // Odd modulus uses the Montgomery path; verify it agrees with the
+        // reference remainder-based squaring.
+        let puzzle = RswPuzzle::new(6, &[0x07], &[0x01, 0x00, 0x01]); // N = 65537
+        let mut expected = puzzle.base().clone();
+        for _ in 0..64 {
+            expected.square_mut();
+            expected %= puzzle.modulus();
+        }
+        assert_eq!(puzzle.evaluate(), expected);
+    }
+
+    #[test]
+    fn test_montgomery_pow_mod_matches_rug() {
+        // Montgomery-form exponentiation must agree with the generic backend.
+        let modulus = rug::Integer::from(65537u32);
+        let ctx = MontgomeryCtx::new(&modulus).unwrap();
+        let base = rug::Integer::from(12345u32);
+        for exp in [0u32, 1, 2, 7, 1000, 65535] {
+            let exp = rug::Integer::from(exp);
+            let expected = base.clone().pow_mod(&exp, &modulus).unwrap();
+            assert_eq!(ctx.pow_mod(&base, &exp, &modulus), expected, "exp={exp}");
+        }
+    }
+
+    #[test]
+    fn test_even_modulus_falls_back() {
+        // Even modulus must use the fallback path and still be correct.
+        let puzzle = RswPuzzle::new(4, &[0x03], &[0x01, 0x00]); // N = 256 (even)
+        let mut expected = puzzle.base().clone();
+        for _ in 0..16 {
+            expected.square_mut();
+            expected %= puzzle.modulus();
+        }
+        assert_eq!(puzzle.evaluate(), expected);
+    }
+
+    #[test]
+    fn test_trapdoor_matches_evaluate() {
+        // N = 11 * 13 = 143, x = 2, T = 2^5.
+        let slow = RswPuzzle::new(5, &[0x02], &[143]);
+        let fast = RswPuzzle::with_trapdoor(5, &[0x02], &[11], &[13]);
+        assert_eq!(fast.modulus(), slow.modulus());
+        assert_eq!(fast.solve_with_trapdoor().unwrap(), slow.evaluate());
+    }
+
+    #[test]
+    fn test_generate_produces_valid_modulus() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+        use rug::integer::IsPrime;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let (puzzle, p, q) = RswPuzzle::generate(5, 64, false, &mut rng);
+
+        // Factors are prime and multiply to the modulus.
+        assert_ne!(p.is_probably_prime(40), IsPrime::No);
+        assert_ne!(q.is_probably_prime(40), IsPrime::No);
+        assert_eq!(*puzzle.modulus(), rug::Integer::from(&p * &q));
+
+        // Trapdoor solve agrees with slow evaluate.
+        assert_eq!(puzzle.solve_with_trapdoor().unwrap(), puzzle.evaluate());
+    }
+
+    #[test]
+    fn test_generate_safe_primes() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+        use rug::integer::IsPrime;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(11);
+        let (_puzzle, p, _q) = RswPuzzle::generate(4, 48, true, &mut rng);
+
+        // p = 2p' + 1 with p' prime.
+        let p_prime = rug::Integer::from(&p - 1) / 2;
+        assert_ne!(p_prime.is_probably_prime(40), IsPrime::No);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize_feature_preserves_trapdoor_solve() {
+        // Scrubbing secrets on drop must not change the computed output.
+        let fast = RswPuzzle::with_trapdoor(5, &[0x02], &[11], &[13]);
+        let slow = RswPuzzle::new(5, &[0x02], &[143]);
+        assert_eq!(fast.solve_with_trapdoor().unwrap(), slow.evaluate());
+    }
+
+    #[test]
+    fn test_solve_without_trapdoor_is_none() {
+        let puzzle = RswPuzzle::new(4, &[0x02], &[143]);
+        assert!(puzzle.solve_with_trapdoor().is_none());
+    }
+
+    #[test]
+    fn test_prove_and_verify_roundtrip() {
+        let puzzle = RswPuzzle::new(5, &[0x02], &[0x01, 0x00, 0x01]); // N = 65537
+        let y = puzzle.evaluate();
+        let proof = puzzle.prove();
+        assert!(puzzle.verify(&y, &proof), "Valid proof should verify");
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_proof() {
+        let puzzle = RswPuzzle::new(5, &[0x02], &[0x01, 0x00, 0x01]);
+        let y = puzzle.evaluate();
+        let tampered = match puzzle.prove() {
+            Proof::Wesolowski { pi } => Proof::Wesolowski { pi: pi + 1 },
+            other => other,
+        };
+        assert!(!puzzle.verify(&y, &tampered), "Tampered proof should fail");
+    }
+
+    #[test]
+    fn test_verify_rejects_claimed_y_mismatching_the_proof() {
+        // A caller-supplied `y` that the prover never produced must fail even
+        // with an otherwise-genuine proof: `verify` checks the proof against
+        // the claimed value instead of trusting/recomputing it.
+        let puzzle = RswPuzzle::new(5, &[0x02], &[0x01, 0x00, 0x01]);
+        let proof = puzzle.prove();
+        let wrong_y = rug::Integer::from(puzzle.evaluate() + 1) % puzzle.modulus();
+        assert!(!puzzle.verify(&wrong_y, &proof), "Mismatched y should fail");
+    }
+
+    #[test]
+    fn test_pietrzak_prove_and_verify_roundtrip() {
+        let puzzle = RswPuzzle::new(5, &[0x02], &[0x01, 0x00, 0x01]); // N = 65537
+        let y = puzzle.evaluate();
+        let proof = puzzle.prove_with(ProofSystem::Pietrzak);
+        assert!(matches!(proof, Proof::Pietrzak { .. }));
+        assert!(puzzle.verify(&y, &proof), "Valid Pietrzak proof should verify");
+    }
+
+    #[test]
+    fn test_pietrzak_rejects_tampered_proof() {
+        let puzzle = RswPuzzle::new(5, &[0x02], &[0x01, 0x00, 0x01]);
+        let y = puzzle.evaluate();
+        let tampered = match puzzle.prove_with(ProofSystem::Pietrzak) {
+            Proof::Pietrzak { mut mus } => {
+                mus[0] += 1;
+                Proof::Pietrzak { mus }
+            }
+            other => other,
+        };
+        assert!(!puzzle.verify(&y, &tampered), "Tampered Pietrzak proof should fail");
+    }
+
+    #[test]
+    fn test_pietrzak_verify_rejects_claimed_y_mismatching_the_proof() {
+        let puzzle = RswPuzzle::new(5, &[0x02], &[0x01, 0x00, 0x01]);
+        let proof = puzzle.prove_with(ProofSystem::Pietrzak);
+        let wrong_y = rug::Integer::from(puzzle.evaluate() + 1) % puzzle.modulus();
+        assert!(!puzzle.verify(&wrong_y, &proof), "Mismatched y should fail");
+    }
 }
@@ -0,0 +1,495 @@
+//! Fixed-width, heap-free big-integer backend for VDF verification.
+//!
+//! Everything else in the crate leans on [`rug::Integer`], which wraps GMP and
+//! therefore cannot build on `no_std`/wasm targets — exactly where Wesolowski
+//! proofs are often *verified* (light clients, on-chain-adjacent verifiers).
+//! This module provides a small set of fixed-width unsigned integers backed by
+//! limb arrays, whose modular arithmetic runs without GMP and without any heap
+//! allocation.
+//!
+//! RFC-sized RSA moduli top out at 4096 bits, so the two widths [`U2048`] and
+//! [`U4096`] cover every practical verifier. The [`ModBackend`] trait is
+//! implemented for both this backend and `rug::Integer`; the verifiers in this
+//! crate are not generic over it and run on `rug::Integer` only.
+//!
+//! The `rug` path remains the default prover backend. Under `fixed-bigint`,
+//! [`FixedMontgomery`] runs the squaring chains of `RswPuzzle::evaluate` and
+//! `WesolowskiProver::prove` when the modulus is odd and fits. This module
+//! itself still calls `rug` for conversions and constant setup, so it does not
+//! build where GMP is unavailable.
+#![cfg(feature = "fixed-bigint")]
+
+use core::cmp::Ordering;
+
+/// Modular-arithmetic operations a verification backend must provide.
+///
+/// Implemented for both [`rug::Integer`] (the default, GMP-backed path) and the
+/// fixed-width [`FixedUint`] types here, so callers can stay generic over the
+/// representation.
+pub trait ModBackend: Clone + PartialEq {
+    /// Interprets `bytes` as a big-endian unsigned integer.
+    fn from_be_bytes(bytes: &[u8]) -> Self;
+    /// Returns whether the value is zero.
+    fn is_zero(&self) -> bool;
+    /// Returns whether `self >= other`.
+    fn ge(&self, other: &Self) -> bool;
+    /// Computes `self · rhs mod modulus`.
+    fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self;
+    /// Computes `self^exp mod modulus`, with `exp` given big-endian.
+    fn pow_mod_be(&self, exp_be: &[u8], modulus: &Self) -> Self;
+}
+
+/// A fixed-width unsigned integer of `L` 64-bit limbs, stored little-endian
+/// (limb 0 is least significant). `L = 32` is 2048 bits, `L = 64` is 4096 bits.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FixedUint<const L: usize> {
+    limbs: [u64; L],
+}
+
+/// 2048-bit fixed-width unsigned integer.
+pub type U2048 = FixedUint<32>;
+/// 4096-bit fixed-width unsigned integer.
+pub type U4096 = FixedUint<64>;
+
+impl<const L: usize> FixedUint<L> {
+    /// The additive identity.
+    pub const ZERO: Self = FixedUint { limbs: [0; L] };
+
+    /// Builds a value from big-endian bytes, taking the low `L` limbs when the
+    /// input is wider than the backend.
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        let mut limbs = [0u64; L];
+        // Walk the bytes from least significant (end of slice) upward.
+        for (i, &byte) in bytes.iter().rev().enumerate() {
+            let limb = i / 8;
+            if limb >= L {
+                break;
+            }
+            limbs[limb] |= (byte as u64) << ((i % 8) * 8);
+        }
+        FixedUint { limbs }
+    }
+
+    /// Returns whether the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&l| l == 0)
+    }
+
+    /// Returns the bit at `index` (0 = least significant).
+    fn bit(&self, index: usize) -> bool {
+        let limb = index / 64;
+        if limb >= L {
+            return false;
+        }
+        (self.limbs[limb] >> (index % 64)) & 1 == 1
+    }
+
+    /// Adds `rhs`, returning the carry out of the top limb.
+    fn add_assign(&mut self, rhs: &Self) -> bool {
+        let mut carry = 0u128;
+        for i in 0..L {
+            let sum = self.limbs[i] as u128 + rhs.limbs[i] as u128 + carry;
+            self.limbs[i] = sum as u64;
+            carry = sum >> 64;
+        }
+        carry != 0
+    }
+
+    /// Subtracts `rhs` (assumed `<= self`), returning the borrow out.
+    fn sub_assign(&mut self, rhs: &Self) -> bool {
+        let mut borrow = 0i128;
+        for i in 0..L {
+            let diff = self.limbs[i] as i128 - rhs.limbs[i] as i128 - borrow;
+            if diff < 0 {
+                self.limbs[i] = (diff + (1i128 << 64)) as u64;
+                borrow = 1;
+            } else {
+                self.limbs[i] = diff as u64;
+                borrow = 0;
+            }
+        }
+        borrow != 0
+    }
+
+    /// Full `L×L -> 2L` schoolbook product.
+    fn wide_mul(&self, rhs: &Self) -> [u64; 128] {
+        debug_assert!(2 * L <= 128, "fixed widths stay within the wide buffer");
+        let mut out = [0u64; 128];
+        for i in 0..L {
+            let mut carry = 0u128;
+            for j in 0..L {
+                let cur = out[i + j] as u128 + self.limbs[i] as u128 * rhs.limbs[j] as u128 + carry;
+                out[i + j] = cur as u64;
+                carry = cur >> 64;
+            }
+            out[i + L] = out[i + L].wrapping_add(carry as u64);
+        }
+        out
+    }
+}
+
+impl<const L: usize> PartialOrd for FixedUint<L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const L: usize> Ord for FixedUint<L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for i in (0..L).rev() {
+            match self.limbs[i].cmp(&other.limbs[i]) {
+                Ordering::Equal => continue,
+                non_eq => return non_eq,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl<const L: usize> ModBackend for FixedUint<L> {
+    fn from_be_bytes(bytes: &[u8]) -> Self {
+        FixedUint::from_be_bytes(bytes)
+    }
+
+    fn is_zero(&self) -> bool {
+        FixedUint::is_zero(self)
+    }
+
+    fn ge(&self, other: &Self) -> bool {
+        self >= other
+    }
+
+    fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        let product = self.wide_mul(rhs);
+        // Binary long division: fold the `2L`-limb product into the remainder
+        // one bit at a time, reducing by `modulus` whenever it fits.
+        let mut rem = FixedUint::<L>::ZERO;
+        for bit in (0..(2 * L * 64)).rev() {
+            // rem <<= 1, capturing the carry out of the top limb.
+            let mut carry = 0u64;
+            for limb in rem.limbs.iter_mut() {
+                let next = *limb >> 63;
+                *limb = (*limb << 1) | carry;
+                carry = next;
+            }
+            // Bring in the next product bit.
+            let limb = bit / 64;
+            if (product[limb] >> (bit % 64)) & 1 == 1 {
+                rem.limbs[0] |= 1;
+            }
+            // A carry out of the top limb means rem already exceeds the modulus.
+            if carry != 0 || rem >= *modulus {
+                rem.sub_assign(modulus);
+            }
+        }
+        rem
+    }
+
+    fn pow_mod_be(&self, exp_be: &[u8], modulus: &Self) -> Self {
+        // Montgomery-free square-and-multiply; `one` is the multiplicative
+        // identity reduced mod the modulus.
+        let mut one = FixedUint::<L>::ZERO;
+        one.limbs[0] = 1;
+        if *modulus <= one {
+            return FixedUint::<L>::ZERO;
+        }
+        let base = {
+            let mut b = *self;
+            while b >= *modulus {
+                b.sub_assign(modulus);
+            }
+            b
+        };
+        let exp = FixedUint::<L>::from_be_bytes(exp_be);
+        let mut acc = one;
+        for bit in (0..(L * 64)).rev() {
+            acc = acc.mul_mod(&acc, modulus);
+            if exp.bit(bit) {
+                acc = acc.mul_mod(&base, modulus);
+            }
+        }
+        acc
+    }
+}
+
+/// Default GMP-backed implementation, so verification code can stay generic
+/// over [`ModBackend`] and be exercised against both representations.
+impl ModBackend for rug::Integer {
+    fn from_be_bytes(bytes: &[u8]) -> Self {
+        rug::Integer::from_digits(bytes, rug::integer::Order::MsfBe)
+    }
+
+    fn is_zero(&self) -> bool {
+        rug::Integer::is_zero(self)
+    }
+
+    fn ge(&self, other: &Self) -> bool {
+        self >= other
+    }
+
+    fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        let mut out = rug::Integer::from(self * rhs);
+        out %= modulus;
+        out
+    }
+
+    fn pow_mod_be(&self, exp_be: &[u8], modulus: &Self) -> Self {
+        let exp = rug::Integer::from_digits(exp_be, rug::integer::Order::MsfBe);
+        self.clone()
+            .pow_mod(&exp, modulus)
+            .expect("exponent is non-negative")
+    }
+}
+
+impl<const L: usize> FixedUint<L> {
+    /// The multiplicative identity.
+    fn one() -> Self {
+        let mut v = FixedUint::<L>::ZERO;
+        v.limbs[0] = 1;
+        v
+    }
+
+    /// Builds a value from a [`rug::Integer`], keeping its low `L` limbs.
+    pub fn from_rug(value: &rug::Integer) -> Self {
+        Self::from_be_bytes(&value.to_digits::<u8>(rug::integer::Order::MsfBe))
+    }
+
+    /// Reconstructs the [`rug::Integer`] this value represents.
+    pub fn to_rug(&self) -> rug::Integer {
+        let mut out = rug::Integer::new();
+        for &limb in self.limbs.iter().rev() {
+            out <<= 64;
+            out += limb;
+        }
+        out
+    }
+}
+
+/// Montgomery-reduction context over `L` limbs for an odd modulus.
+///
+/// This is the limb-array analogue of [`MontgomeryCtx`](super::MontgomeryCtx):
+/// it backs the `x -> x^2 mod N` hot loop with Separated-Operand-Scanning
+/// Montgomery multiplication, so the whole squaring chain runs on the stack
+/// with no heap allocation, giving the VDF constant-memory timing across
+/// platforms. Construction goes through `rug`, so the context does not make
+/// the prover buildable on `no_std`/wasm.
+///
+/// `R = 2^(64·L)`; the constants are derived once at construction, off the hot
+/// path: `R^2 mod N` via `rug`, and `-N^{-1} mod 2^64` by Newton iteration
+/// (`inv_mod_2_64`).
+pub struct FixedMontgomery<const L: usize> {
+    modulus: FixedUint<L>,
+    r2: FixedUint<L>,
+    n_prime: u64,
+}
+
+impl<const L: usize> FixedMontgomery<L> {
+    /// Builds the context for an odd modulus `> 1`, or `None` for an even or
+    /// degenerate modulus (which falls back to the `rug` path).
+    pub fn new(modulus: &FixedUint<L>) -> Option<Self> {
+        if modulus.limbs[0] & 1 == 0 || *modulus <= FixedUint::<L>::one() {
+            return None;
+        }
+        let n_rug = modulus.to_rug();
+        let r = rug::Integer::from(1) << (L as u32 * 64);
+        let r2 = FixedUint::<L>::from_rug(&(rug::Integer::from(&r * &r) % &n_rug));
+        Some(FixedMontgomery {
+            modulus: *modulus,
+            r2,
+            n_prime: inv_mod_2_64(modulus.limbs[0]).wrapping_neg(),
+        })
+    }
+
+    /// The modulus this context reduces by.
+    pub fn modulus(&self) -> &FixedUint<L> {
+        &self.modulus
+    }
+
+    /// Montgomery product `a · b · R^{-1} mod N` via separated operand scanning:
+    /// a full `2L`-limb product followed by an in-place `L`-step reduction.
+    pub fn mont_mul(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        let n = &self.modulus.limbs;
+        // Widen the product with two guard limbs for the reduction carry.
+        let mut t = [0u64; 130];
+        t[..128].copy_from_slice(&a.wide_mul(b));
+
+        for i in 0..L {
+            let m = t[i].wrapping_mul(self.n_prime);
+            let mut carry = 0u128;
+            for j in 0..L {
+                let sum = t[i + j] as u128 + m as u128 * n[j] as u128 + carry;
+                t[i + j] = sum as u64;
+                carry = sum >> 64;
+            }
+            // Propagate the reduction carry through the high limbs.
+            let mut k = i + L;
+            while carry != 0 {
+                let sum = t[k] as u128 + carry;
+                t[k] = sum as u64;
+                carry = sum >> 64;
+                k += 1;
+            }
+        }
+
+        let mut res = FixedUint::<L>::ZERO;
+        res.limbs.copy_from_slice(&t[L..2 * L]);
+        // A carry beyond the top limb, or `res >= N`, needs one subtraction.
+        if t[2 * L] != 0 || res >= self.modulus {
+            res.sub_assign(&self.modulus);
+        }
+        res
+    }
+
+    /// Converts `a` into Montgomery form `a·R mod N`.
+    pub fn to_form(&self, a: &FixedUint<L>) -> FixedUint<L> {
+        self.mont_mul(a, &self.r2)
+    }
+
+    /// Converts `a` out of Montgomery form.
+    pub fn from_form(&self, a: &FixedUint<L>) -> FixedUint<L> {
+        self.mont_mul(a, &FixedUint::<L>::one())
+    }
+
+    /// Computes `base^(2^t) mod N` entirely in Montgomery form.
+    ///
+    /// `should_stop` is polled every `check_interval` squarings with the step
+    /// count; returning `true` aborts the chain and yields `None`.
+    pub fn square_chain(
+        &self,
+        base: &FixedUint<L>,
+        t: u64,
+        check_interval: u64,
+        mut should_stop: impl FnMut(u64) -> bool,
+    ) -> Option<FixedUint<L>> {
+        let mut y = self.to_form(base);
+        for i in 1..=t {
+            if i % check_interval == 0 && should_stop(i) {
+                return None;
+            }
+            y = self.mont_mul(&y, &y);
+        }
+        Some(self.from_form(&y))
+    }
+
+    /// Computes the Wesolowski proof element `base^(⌊2^t / p⌋) mod N` by the
+    /// same long-division-over-squarings the `rug` prover uses, but in
+    /// Montgomery form. `should_stop` is polled as in [`square_chain`].
+    pub fn pi_chain(
+        &self,
+        base: &FixedUint<L>,
+        p: &FixedUint<L>,
+        t: u64,
+        check_interval: u64,
+        mut should_stop: impl FnMut(u64) -> bool,
+    ) -> Option<FixedUint<L>> {
+        let base_form = self.to_form(base);
+        let mut pi = self.to_form(&FixedUint::<L>::one());
+        let mut r = FixedUint::<L>::one();
+        for i in 1..=t {
+            if i % check_interval == 0 && should_stop(i) {
+                return None;
+            }
+            pi = self.mont_mul(&pi, &pi);
+            let rc = r;
+            r.add_assign(&rc); // r <<= 1
+            if r >= *p {
+                r.sub_assign(p);
+                pi = self.mont_mul(&pi, &base_form);
+            }
+        }
+        Some(self.from_form(&pi))
+    }
+}
+
+/// Computes `x^{-1} mod 2^64` for odd `x` via Newton iteration (doubling the
+/// number of correct low bits each round; six rounds cover all 64).
+fn inv_mod_2_64(x: u64) -> u64 {
+    let mut inv = 1u64;
+    for _ in 0..6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(x.wrapping_mul(inv)));
+    }
+    inv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cross-checks a fixed-width operation against `rug` for small operands
+    /// that embed comfortably inside the backend width.
+    fn check(a: u64, b: u64, m: u64) {
+        let af = U2048::from_be_bytes(&a.to_be_bytes());
+        let bf = U2048::from_be_bytes(&b.to_be_bytes());
+        let mf = U2048::from_be_bytes(&m.to_be_bytes());
+
+        let got = af.mul_mod(&bf, &mf);
+        let expected = U2048::from_be_bytes(
+            &(((a as u128 * b as u128) % m as u128) as u64).to_be_bytes(),
+        );
+        assert_eq!(got, expected, "{a} * {b} mod {m}");
+    }
+
+    #[test]
+    fn test_mul_mod_matches_reference() {
+        check(12345, 67890, 65537);
+        check(u32::MAX as u64, u32::MAX as u64, 4294967291);
+        check(0, 99, 101);
+        check(100, 0, 101);
+    }
+
+    #[test]
+    fn test_pow_mod_matches_rug() {
+        let modulus = U2048::from_be_bytes(&65537u64.to_be_bytes());
+        let base = U2048::from_be_bytes(&12345u64.to_be_bytes());
+        for exp in [0u64, 1, 2, 7, 1000, 65535] {
+            let got = base.pow_mod_be(&exp.to_be_bytes(), &modulus);
+            let expected = rug::Integer::from(12345u32)
+                .pow_mod(&rug::Integer::from(exp), &rug::Integer::from(65537u32))
+                .unwrap();
+            let expected = U2048::from_be_bytes(
+                &expected.to_digits::<u8>(rug::integer::Order::MsfBe),
+            );
+            assert_eq!(got, expected, "12345^{exp} mod 65537");
+        }
+    }
+
+    #[test]
+    fn test_montgomery_square_chain_matches_rug() {
+        // base^(2^t) mod N over the fixed backend must equal the GMP result.
+        let modulus = rug::Integer::from(0x1_0001u32); // 65537, odd
+        let base = rug::Integer::from(12345u32);
+        let n = U2048::from_rug(&modulus);
+        let mont = FixedMontgomery::new(&n).expect("odd modulus");
+
+        for t in [0u64, 1, 5, 16, 64] {
+            let got = mont
+                .square_chain(&U2048::from_rug(&base), t, 1, |_| false)
+                .expect("not cancelled")
+                .to_rug();
+            // Reference: repeated squaring in rug.
+            let mut y = base.clone();
+            for _ in 0..t {
+                y.square_mut();
+                y %= &modulus;
+            }
+            assert_eq!(got, y, "base^(2^{t}) mod N");
+        }
+    }
+
+    #[test]
+    fn test_montgomery_conversions_round_trip() {
+        let n = U2048::from_rug(&rug::Integer::from(0x1_0001u32));
+        let mont = FixedMontgomery::new(&n).unwrap();
+        let a = U2048::from_rug(&rug::Integer::from(40000u32));
+        assert_eq!(mont.from_form(&mont.to_form(&a)), a);
+    }
+
+    #[test]
+    fn test_from_be_bytes_ordering() {
+        let small = U4096::from_be_bytes(&[0x01]);
+        let big = U4096::from_be_bytes(&[0x01, 0x00]);
+        assert!(big > small);
+        assert!(!big.is_zero());
+        assert!(U4096::ZERO.is_zero());
+    }
+}
@@ -1,5 +1,81 @@
 use crate::vdf::{Solution, WesolowskiVdf};
 
+pub mod group;
+pub mod solidity;
+
+/// A space-reduced [`Solution`] that drops the low bytes of the output `y`.
+///
+/// The soundness of Wesolowski rests on the challenge prime being bound to the
+/// *true* output: `p = hash_to_prime(x ‖ y)`. Binding it to `π` instead is
+/// fatal — the verification equation `y = x^r · π^p (mod N)` then holds by
+/// construction for any in-range `π`, so an attacker forges a proof with no
+/// delay computation at all. The compressed form therefore still commits to the
+/// real output, transmitting only its high-order bytes (`output_prefix`): these
+/// both feed the challenge hash and pin the reconstruction, while the
+/// incompressible low bytes are recovered from the equation. A `prefix` of
+/// around half of `y`'s length keeps the forgery probability negligible
+/// (≈ `2^{-8·prefix_len}`) while still roughly halving the wire size, mirroring
+/// chiavdf's "compress y" option.
+///
+/// Produce one with [`WesolowskiVerifier::compress`] and check it with
+/// [`WesolowskiVerifier::verify_compressed`].
+pub struct CompressedSolution {
+    /// Big-endian proof element `π`.
+    pub first: Vec<u8>,
+    /// High-order bytes of the big-endian output `y`. They bind the Fiat–Shamir
+    /// challenge to the true output and confirm the reconstructed `y`.
+    pub output_prefix: Vec<u8>,
+}
+
+/// One link of an N-Wesolowski chained proof.
+///
+/// A long delay of `T` squarings is split into consecutive segments; segment
+/// `i` carries its intermediate output `y_i`, the proof element `π_i` that
+/// attests the `t_i` squarings from the previous output to `y_i`, and the
+/// segment length `t_i` itself. The segment lengths sum to the full delay `T`.
+pub struct NWesolowskiSegment {
+    /// Big-endian output `y_i` of this segment.
+    pub y: Vec<u8>,
+    /// Big-endian proof element `π_i` for this segment.
+    pub pi: Vec<u8>,
+    /// Number of squarings `t_i` this segment covers.
+    pub iterations: rug::Integer,
+}
+
+/// Why a Wesolowski solution failed verification.
+///
+/// [`WesolowskiVerifier::verify`] collapses every case to `false`;
+/// [`WesolowskiVerifier::verify_detailed`] returns one of these instead so an
+/// integrator can log exactly why a received proof was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    /// `π` or `y` is `≥ N`, so it is not a residue modulo `N`.
+    ElementOutOfRange,
+    /// `π` or `y` is zero.
+    ZeroElement,
+    /// Deriving the challenge prime `p = hash_to_prime(x ‖ y)` failed.
+    HashToPrimeFailed,
+    /// A modular exponentiation failed (e.g. a non-invertible base).
+    ModPowFailed,
+    /// The verification equation `y == x^r · π^p (mod N)` did not hold.
+    OutputMismatch,
+}
+
+impl std::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            VerifyError::ElementOutOfRange => "proof element is out of range [1, N-1]",
+            VerifyError::ZeroElement => "proof element is zero",
+            VerifyError::HashToPrimeFailed => "hash-to-prime of the transcript failed",
+            VerifyError::ModPowFailed => "modular exponentiation failed",
+            VerifyError::OutputMismatch => "verification equation did not hold",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
 pub struct WesolowskiVerifier<'a> {
     vdf: &'a WesolowskiVdf,
 }
@@ -23,7 +99,21 @@ impl<'a> WesolowskiVerifier<'a> {
     ///
     /// # Returns
     /// * `true` if the solution is valid, `false` otherwise
+    ///
+    /// This is a thin wrapper over [`verify_detailed`](Self::verify_detailed);
+    /// use that method when the rejection reason matters.
     pub fn verify(&self, solution: &Solution) -> bool {
+        self.verify_detailed(solution).is_ok()
+    }
+
+    /// Verifies a solution, returning a [`VerifyError`] describing the first
+    /// failed check instead of collapsing every failure mode to `false`.
+    ///
+    /// The checks run in the same order as [`verify`](Self::verify): range and
+    /// zero guards, then challenge derivation, then the modular exponentiations,
+    /// then the final equation. This lets integrators log exactly why a received
+    /// proof was rejected.
+    pub fn verify_detailed(&self, solution: &Solution) -> Result<(), VerifyError> {
         let modulus = self.vdf.modulus(); // N
         let base = self.vdf.base(); // x
         let iterations = self.vdf.iterations(); // T
@@ -32,8 +122,11 @@ impl<'a> WesolowskiVerifier<'a> {
         let sigma = rug::Integer::from_digits(&solution.second, rug::integer::Order::MsfBe); // sigma = y = solution
 
         // Check that sigma (y) and pi are not zero and within valid range
-        if sigma.is_zero() || pi.is_zero() || sigma >= *modulus || pi >= *modulus {
-            return false;
+        if sigma.is_zero() || pi.is_zero() {
+            return Err(VerifyError::ZeroElement);
+        }
+        if sigma >= *modulus || pi >= *modulus {
+            return Err(VerifyError::ElementOutOfRange);
         }
 
         // Prepare xy for hashing: xy = x || y (concatenate x and y)
@@ -46,37 +139,420 @@ impl<'a> WesolowskiVerifier<'a> {
         };
 
         // Hash xy to get prime p
-        let p = match self.vdf.hash_to_prime(&xy) {
-            Ok(prime) => prime,
-            Err(_) => return false, // Hash-to-prime failed
-        };
+        let p = self
+            .vdf
+            .hash_to_prime(&xy)
+            .map_err(|_| VerifyError::HashToPrimeFailed)?;
 
         // Compute r = 2^T mod p
-        let r = match rug::Integer::from(2).pow_mod(iterations, &p) {
-            Ok(result) => result,
-            Err(_) => return false, // Modular exponentiation failed
+        let r = rug::Integer::from(2)
+            .pow_mod(iterations, &p)
+            .map_err(|_| VerifyError::ModPowFailed)?;
+
+        // Compute h = x^r * pi^p mod N in a single squaring chain via Shamir's
+        // trick, rather than two independent modular exponentiations.
+        let h = multi_exp_two(base, &r, &pi, &p, modulus);
+
+        // Verification: check if y == h
+        if sigma == h {
+            Ok(())
+        } else {
+            Err(VerifyError::OutputMismatch)
+        }
+    }
+
+    /// Verifies an N-Wesolowski chained proof.
+    ///
+    /// Mirrors chiavdf's `CheckProofOfTimeNWesolowski`: a single delay of `T`
+    /// squarings broken into `witness_type` consecutive segments so the prover
+    /// can checkpoint and keep memory bounded. Starting from `x_0 = g`, each
+    /// segment `i` is checked exactly like the single-proof path —
+    /// `p = hash_to_prime(x_{i-1} ‖ y_i)`, `r = 2^{t_i} mod p`, and
+    /// `y_i ≟ x_{i-1}^r · π_i^p (mod N)` — then `x_i = y_i` carries into the
+    /// next segment. The last segment's `y_n` is the VDF output.
+    ///
+    /// # Arguments
+    /// * `segments` - the ordered `(y_i, π_i, t_i)` links
+    /// * `witness_type` - the expected segment count `n`
+    ///
+    /// # Returns
+    /// * `true` iff every segment checks out, the count matches `witness_type`,
+    ///   and the segment lengths sum to the configured delay `T`
+    pub fn verify_n_wesolowski(
+        &self,
+        segments: &[NWesolowskiSegment],
+        witness_type: usize,
+    ) -> bool {
+        // Depth must match the declared witness type, and an empty chain is no
+        // proof at all.
+        if segments.is_empty() || segments.len() != witness_type {
+            return false;
+        }
+
+        let modulus = self.vdf.modulus();
+        let modulus_bits = modulus.significant_bits();
+
+        // The segment lengths must reconstruct exactly the full delay T.
+        let mut total = rug::Integer::from(0);
+        for segment in segments {
+            total += &segment.iterations;
+        }
+        if &total != self.vdf.iterations() {
+            return false;
+        }
+
+        // x_0 = g, then x_i = y_i after each accepted segment.
+        let mut x = rug::Integer::from(self.vdf.base());
+
+        for segment in segments {
+            let pi = rug::Integer::from_digits(&segment.pi, rug::integer::Order::MsfBe);
+            let y = rug::Integer::from_digits(&segment.y, rug::integer::Order::MsfBe);
+
+            // Per-segment range checks, identical to the single-proof path.
+            if y.is_zero() || pi.is_zero() || y >= *modulus || pi >= *modulus {
+                return false;
+            }
+
+            // p = hash_to_prime(x_{i-1} || y_i).
+            let xy = {
+                let mut temp = x.clone();
+                temp <<= modulus_bits;
+                temp + &y
+            };
+            let p = match self.vdf.hash_to_prime(&xy) {
+                Ok(prime) => prime,
+                Err(_) => return false,
+            };
+
+            // r = 2^{t_i} mod p.
+            let r = match rug::Integer::from(2).pow_mod(&segment.iterations, &p) {
+                Ok(result) => result,
+                Err(_) => return false,
+            };
+
+            // Check y_i == x_{i-1}^r * pi_i^p mod N.
+            let x_r = match x.clone().pow_mod(&r, modulus) {
+                Ok(result) => result,
+                Err(_) => return false,
+            };
+            let pi_p = match pi.pow_mod(&p, modulus) {
+                Ok(result) => result,
+                Err(_) => return false,
+            };
+
+            let mut h = x_r;
+            h *= pi_p;
+            h %= modulus;
+            if h != y {
+                return false;
+            }
+
+            // Carry the output forward as the next segment's input.
+            x = y;
+        }
+
+        true
+    }
+
+    /// Verifies a self-describing segment chain produced by
+    /// [`prove_segmented`](crate::prover::WesolowskiProver::prove_segmented).
+    ///
+    /// Same checks as [`verify_n_wesolowski`](Self::verify_n_wesolowski), but the
+    /// segment count is taken from the chain itself: every link must hold, the
+    /// chain must compose from the puzzle base `g` through each `x_i`, and the
+    /// segment lengths must sum to the configured delay `T` so the final `x_k`
+    /// is the VDF output at full delay. A node can reject a bad chain at the
+    /// first failing link.
+    pub fn verify_chain(&self, segments: &[NWesolowskiSegment]) -> bool {
+        self.verify_n_wesolowski(segments, segments.len())
+    }
+
+    /// Derives the compressed-path challenge `p = hash_to_prime(x ‖ y_high)`
+    /// from the transmitted high bytes of the output. The verifier never sees
+    /// the full `y`, so the challenge is bound to its committed prefix — which
+    /// an honest prover takes from the true output — keeping the hash input
+    /// independent of the attacker-chosen `π`.
+    fn compressed_challenge(&self, output_prefix: &[u8]) -> Result<rug::Integer, String> {
+        let modulus_bits = self.vdf.modulus().significant_bits();
+        let prefix = rug::Integer::from_digits(output_prefix, rug::integer::Order::MsfBe);
+        let x_prefix = {
+            let mut temp = rug::Integer::from(self.vdf.base());
+            temp <<= modulus_bits;
+            temp + &prefix
         };
+        self.vdf.hash_to_prime(&x_prefix)
+    }
 
-        // Compute x^r mod N (use reference to avoid cloning)
-        let x_r = match rug::Integer::from(base).pow_mod(&r, modulus) {
-            Ok(result) => result,
-            Err(_) => return false, // Modular exponentiation failed
+    /// Reconstructs the output `y = x^r · π^p (mod N)` from a compressed proof
+    /// and verifies that it matches the committed `output_prefix`.
+    ///
+    /// The challenge `p = hash_to_prime(x ‖ output_prefix)` is bound to the
+    /// committed high bytes of the true output — never to `π` — so a `π` chosen
+    /// to satisfy the equation for some unrelated `y` is rejected unless its
+    /// reconstructed output happens to share all `output_prefix.len()` leading
+    /// bytes, which for a PRF-like output occurs only with probability
+    /// `2^{-8·output_prefix.len()}`. Returns `None` on an out-of-range `π`, a
+    /// prefix mismatch, or any arithmetic failure.
+    fn reconstruct_output(&self, solution: &CompressedSolution) -> Option<rug::Integer> {
+        let modulus = self.vdf.modulus();
+        let base = self.vdf.base();
+        let iterations = self.vdf.iterations();
+
+        let pi = rug::Integer::from_digits(&solution.first, rug::integer::Order::MsfBe);
+        if pi.is_zero() || pi >= *modulus {
+            return None;
+        }
+
+        let p = self.compressed_challenge(&solution.output_prefix).ok()?;
+        let r = rug::Integer::from(2).pow_mod(iterations, &p).ok()?;
+
+        let y = multi_exp_two(base, &r, &pi, &p, modulus);
+        if y.is_zero() {
+            return None;
+        }
+
+        // The reconstruction is only trusted once its high bytes reproduce the
+        // prefix the challenge was hashed over; otherwise the proof is unbound.
+        let y_bytes = y.to_digits::<u8>(rug::integer::Order::MsfBe);
+        if !prefix_matches(&y_bytes, &solution.output_prefix) {
+            return None;
+        }
+        Some(y)
+    }
+
+    /// Compresses a full [`Solution`] by dropping the low-order `drop_bytes`
+    /// bytes of the output `y` and re-deriving `π` against the prefix-bound
+    /// challenge.
+    ///
+    /// The resulting [`CompressedSolution`] verifies under the same committed
+    /// output as the uncompressed proof, so it carries the full Wesolowski
+    /// soundness. Returns `None` if `y` is zero or out of range, if `T` does
+    /// not fit in a `u32`, or if a modular exponentiation fails; the incoming
+    /// `π` is not read, since a fresh one is derived.
+    pub fn compress(&self, solution: &Solution, drop_bytes: usize) -> Option<CompressedSolution> {
+        let modulus = self.vdf.modulus();
+        let base = self.vdf.base();
+
+        let y = rug::Integer::from_digits(&solution.second, rug::integer::Order::MsfBe);
+        if y.is_zero() || y >= *modulus {
+            return None;
+        }
+
+        let y_bytes = y.to_digits::<u8>(rug::integer::Order::MsfBe);
+        let prefix_start = y_bytes.len().saturating_sub(drop_bytes);
+        let output_prefix = y_bytes[..prefix_start].to_vec();
+
+        // Re-derive π for the prefix-bound challenge: p = H(x ‖ y_high),
+        // q = ⌊2^T / p⌋, π = x^q mod N — the same proof the prover would build.
+        let p = self.compressed_challenge(&output_prefix).ok()?;
+        let two_pow_t = rug::Integer::from(1) << self.vdf.iterations().to_u32()?;
+        let q = rug::Integer::from(&two_pow_t / &p);
+        let pi = rug::Integer::from(base).pow_mod(&q, modulus).ok()?;
+
+        Some(CompressedSolution {
+            first: pi.to_digits::<u8>(rug::integer::Order::MsfBe),
+            output_prefix,
+        })
+    }
+
+    /// Verifies a [`CompressedSolution`] by reconstructing the output `y` and
+    /// confirming it matches the committed `output_prefix`.
+    ///
+    /// The challenge prime is `p = hash_to_prime(x ‖ output_prefix)`,
+    /// `r = 2^T mod p`, and the reconstructed output is
+    /// `y = x^r · π^p (mod N)`. The proof is accepted iff the reconstructed `y`
+    /// is in range and its high bytes equal the committed prefix.
+    ///
+    /// # Returns
+    /// * `true` if the committed prefix matches the reconstructed output
+    pub fn verify_compressed(&self, solution: &CompressedSolution) -> bool {
+        self.reconstruct_output(solution).is_some()
+    }
+
+    /// Reconstructs the full [`Solution`] from a [`CompressedSolution`],
+    /// recovering the dropped low bytes of the output `y`. Returns `None` if
+    /// reconstruction fails or the committed prefix does not match.
+    pub fn decompress(&self, solution: &CompressedSolution) -> Option<Solution> {
+        let y = self.reconstruct_output(solution)?;
+        Some(Solution {
+            first: solution.first.clone(),
+            second: y.to_digits::<u8>(rug::integer::Order::MsfBe),
+        })
+    }
+}
+
+/// Computes `base1^exp1 · base2^exp2 mod modulus` with interleaved
+/// (Strauss/Shamir) two-base exponentiation.
+///
+/// Precomputes the four products `{1, base1, base2, base1·base2} mod modulus`,
+/// then scans the bits of `exp1` and `exp2` from most to least significant
+/// together, squaring the accumulator once per position and multiplying in the
+/// product selected by the current bit pair. This uses a single squaring chain
+/// of length `max(bitlen(exp1), bitlen(exp2))` instead of the two chains two
+/// separate `pow_mod` calls would run, roughly halving the multiplications.
+fn multi_exp_two(
+    base1: &rug::Integer,
+    exp1: &rug::Integer,
+    base2: &rug::Integer,
+    exp2: &rug::Integer,
+    modulus: &rug::Integer,
+) -> rug::Integer {
+    // Precomputed table indexed by (bit1 << 1) | bit2.
+    let b1 = rug::Integer::from(base1 % modulus);
+    let b2 = rug::Integer::from(base2 % modulus);
+    let b12 = {
+        let mut t = rug::Integer::from(&b1 * &b2);
+        t %= modulus;
+        t
+    };
+    let table = [rug::Integer::from(1), b1, b2, b12];
+
+    let bits = exp1.significant_bits().max(exp2.significant_bits());
+    let mut acc = rug::Integer::from(1);
+    for i in (0..bits).rev() {
+        acc.square_mut();
+        acc %= modulus;
+        let index = ((exp1.get_bit(i) as usize) << 1) | (exp2.get_bit(i) as usize);
+        if index != 0 {
+            acc *= &table[index];
+            acc %= modulus;
+        }
+    }
+    acc
+}
+
+/// Returns whether the leading `prefix.len()` bytes of the big-endian `y_bytes`
+/// equal `prefix`, accounting for leading-zero suppression in big-endian
+/// encodings (a committed prefix may carry high zero bytes that `y_bytes` drops).
+fn prefix_matches(y_bytes: &[u8], prefix: &[u8]) -> bool {
+    if prefix.len() > y_bytes.len() {
+        // The prefix is wider than y; it can only match if the extra high bytes
+        // are zero and the remaining high bytes agree.
+        let pad = prefix.len() - y_bytes.len();
+        return prefix[..pad].iter().all(|&b| b == 0) && &prefix[pad..] == y_bytes;
+    }
+    &y_bytes[..prefix.len()] == prefix
+}
+
+/// Verifies many Wesolowski solutions that share a single modulus `N` in one
+/// multi-exponentiation, far faster than `k` independent checks.
+///
+/// For each entry the challenge prime `l_i` and `r_i = 2^{T_i} mod l_i` are
+/// computed as usual, then `k` fresh λ-bit random nonces `α_i` are drawn and the
+/// single combined relation
+/// `∏_i π_i^{α_i·l_i} · ∏_i g_i^{α_i·r_i} ≡ ∏_i y_i^{α_i} (mod N)` is checked.
+/// A single invalid proof breaks the random combination with probability
+/// ≥ 1 − 2^{-λ}, so the batch accepts iff every proof is individually valid.
+///
+/// Edge cases: empty batches are rejected, and if the entries do not all share
+/// the same modulus the function falls back to per-proof verification.
+///
+/// # Arguments
+/// * `pairs` - the `(vdf, solution)` pairs to verify together
+///
+/// # Returns
+/// * `true` if every solution is valid, `false` otherwise
+pub fn verify_batch(pairs: &[(&WesolowskiVdf, &Solution)]) -> bool {
+    // Reject empty batches.
+    if pairs.is_empty() {
+        return false;
+    }
+
+    // Require a shared modulus; otherwise fall back to per-proof verification.
+    let modulus = pairs[0].0.modulus();
+    if !pairs
+        .iter()
+        .all(|(vdf, _)| vdf.modulus() == modulus)
+    {
+        return pairs
+            .iter()
+            .all(|(vdf, solution)| WesolowskiVerifier::new(vdf).verify(solution));
+    }
+
+    // λ-bit nonces drawn fresh per call so an attacker cannot craft offsetting
+    // errors against a predictable combination.
+    const LAMBDA_BITS: u32 = 128;
+
+    let mut lhs = rug::Integer::from(1); // ∏ π_i^{α_i·l_i} · ∏ g_i^{α_i·r_i}
+    let mut rhs = rug::Integer::from(1); // ∏ y_i^{α_i}
+
+    let mut rand_state = fresh_rand_state();
+
+    for (vdf, solution) in pairs {
+        let base = vdf.base();
+        let iterations = vdf.iterations();
+
+        let pi = rug::Integer::from_digits(&solution.first, rug::integer::Order::MsfBe);
+        let y = rug::Integer::from_digits(&solution.second, rug::integer::Order::MsfBe);
+
+        // Range checks mirror the single-proof path.
+        if y.is_zero() || pi.is_zero() || y >= *modulus || pi >= *modulus {
+            return false;
+        }
+
+        let modulus_bits = modulus.significant_bits();
+        let xy = {
+            let mut temp = rug::Integer::from(base);
+            temp <<= modulus_bits;
+            temp + &y
         };
 
-        // Compute pi^p mod N
-        let pi_p = match pi.pow_mod(&p, modulus) {
+        let l = match vdf.hash_to_prime(&xy) {
+            Ok(prime) => prime,
+            Err(_) => return false,
+        };
+        let r = match rug::Integer::from(2).pow_mod(iterations, &l) {
             Ok(result) => result,
-            Err(_) => return false, // Modular exponentiation failed
+            Err(_) => return false,
         };
 
-        // Compute h = (x^r * pi^p) mod N - use more efficient approach
-        let mut h = x_r;
-        h *= pi_p;
-        h %= modulus;
+        // Fresh λ-bit nonce α_i.
+        let alpha = random_bits(&mut rand_state, LAMBDA_BITS);
 
-        // Verification: check if y == h
-        sigma == h
+        // lhs *= π_i^{α_i·l_i} · g_i^{α_i·r_i}
+        let pi_exp = rug::Integer::from(&alpha * &l);
+        let g_exp = rug::Integer::from(&alpha * &r);
+        if let Ok(term) = pi.pow_mod(&pi_exp, modulus) {
+            lhs *= term;
+            lhs %= modulus;
+        } else {
+            return false;
+        }
+        if let Ok(term) = rug::Integer::from(base).pow_mod(&g_exp, modulus) {
+            lhs *= term;
+            lhs %= modulus;
+        } else {
+            return false;
+        }
+
+        // rhs *= y_i^{α_i}
+        if let Ok(term) = y.pow_mod(&alpha, modulus) {
+            rhs *= term;
+            rhs %= modulus;
+        } else {
+            return false;
+        }
     }
+
+    lhs == rhs
+}
+
+/// Creates a randomly-seeded `RandState` for drawing batch nonces. Seeding from
+/// OS entropy ensures nonces are fresh on every call.
+fn fresh_rand_state() -> rug::rand::RandState<'static> {
+    use rand::RngCore;
+    let mut seed_bytes = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut seed_bytes);
+    let seed = rug::Integer::from_digits(&seed_bytes, rug::integer::Order::MsfBe);
+    let mut state = rug::rand::RandState::new();
+    state.seed(&seed);
+    state
+}
+
+/// Draws a `bits`-wide random integer from `state`.
+fn random_bits(state: &mut rug::rand::RandState<'_>, bits: u32) -> rug::Integer {
+    let bound = rug::Integer::from(1) << bits;
+    bound.random_below(state)
 }
 
 #[cfg(test)]
@@ -360,6 +836,250 @@ mod tests {
         assert!(verifier.verify(&solution));
     }
 
+    #[test]
+    fn test_verify_batch_all_valid() {
+        // A batch of valid proofs sharing one modulus should verify.
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01]; // 257
+
+        let vdfs: Vec<_> = [0x02u8, 0x03, 0x04]
+            .iter()
+            .map(|b| WesolowskiVdf::new(lambda, time_bits, vec![*b], modulus.clone()))
+            .collect();
+        let stop_flag = CancellationToken::new();
+        let solutions: Vec<_> = vdfs
+            .iter()
+            .map(|vdf| WesolowskiProver::new(vdf).prove(&stop_flag))
+            .collect();
+
+        let pairs: Vec<_> = vdfs.iter().zip(solutions.iter()).collect();
+        assert!(verify_batch(&pairs), "All-valid batch should verify");
+    }
+
+    #[test]
+    fn test_verify_batch_rejects_empty() {
+        assert!(!verify_batch(&[]), "Empty batch must be rejected");
+    }
+
+    #[test]
+    fn test_verify_batch_detects_bad_proof() {
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01];
+
+        let vdfs: Vec<_> = [0x02u8, 0x03]
+            .iter()
+            .map(|b| WesolowskiVdf::new(lambda, time_bits, vec![*b], modulus.clone()))
+            .collect();
+        let stop_flag = CancellationToken::new();
+        let mut solutions: Vec<_> = vdfs
+            .iter()
+            .map(|vdf| WesolowskiProver::new(vdf).prove(&stop_flag))
+            .collect();
+
+        // Corrupt one proof.
+        solutions[1].first[0] = solutions[1].first[0].wrapping_add(1);
+
+        let pairs: Vec<_> = vdfs.iter().zip(solutions.iter()).collect();
+        assert!(
+            !verify_batch(&pairs),
+            "A single bad proof must break the batch"
+        );
+    }
+
+    #[test]
+    fn test_multi_exp_two_matches_two_pow_mods() {
+        let modulus = rug::Integer::from(257);
+        for (b1, e1, b2, e2) in [
+            (3u32, 20u32, 5u32, 13u32),
+            (2, 0, 7, 9),
+            (11, 7, 2, 0),
+            (255, 31, 128, 17),
+        ] {
+            let base1 = rug::Integer::from(b1);
+            let base2 = rug::Integer::from(b2);
+            let exp1 = rug::Integer::from(e1);
+            let exp2 = rug::Integer::from(e2);
+
+            let combined = multi_exp_two(&base1, &exp1, &base2, &exp2, &modulus);
+
+            let mut expected = base1.clone().pow_mod(&exp1, &modulus).unwrap();
+            expected *= base2.clone().pow_mod(&exp2, &modulus).unwrap();
+            expected %= &modulus;
+
+            assert_eq!(combined, expected, "{b1}^{e1} * {b2}^{e2} mod 257");
+        }
+    }
+
+    #[test]
+    fn test_verify_detailed_reports_reasons() {
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01]; // 257
+        let input = vec![0x02];
+
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let verifier = WesolowskiVerifier::new(&vdf);
+        let stop_flag = CancellationToken::new();
+        let valid = WesolowskiProver::new(&vdf).prove(&stop_flag);
+
+        // A genuine proof succeeds.
+        assert_eq!(verifier.verify_detailed(&valid), Ok(()));
+
+        // Zero element is reported distinctly from an out-of-range one.
+        assert_eq!(
+            verifier.verify_detailed(&Solution {
+                first: vec![0x00],
+                second: vec![0x01],
+            }),
+            Err(VerifyError::ZeroElement)
+        );
+        assert_eq!(
+            verifier.verify_detailed(&Solution {
+                first: vec![0xff, 0xff],
+                second: vec![0x01],
+            }),
+            Err(VerifyError::ElementOutOfRange)
+        );
+
+        // A different-but-in-range output reaches the final equation check.
+        let y = rug::Integer::from_digits(&valid.second, rug::integer::Order::MsfBe);
+        let wrong_y = if y == 1 { 2 } else { 1 };
+        let tampered = Solution {
+            first: valid.first.clone(),
+            second: vec![wrong_y],
+        };
+        assert_eq!(
+            verifier.verify_detailed(&tampered),
+            Err(VerifyError::OutputMismatch)
+        );
+    }
+
+    #[test]
+    fn test_compressed_round_trip() {
+        // Compress a genuine proof: the prefix commits to the true output, so
+        // reconstruction recovers the same y the uncompressed proof carries.
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01]; // 257
+        let input = vec![0x02];
+
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let verifier = WesolowskiVerifier::new(&vdf);
+        let stop_flag = CancellationToken::new();
+
+        let solution = WesolowskiProver::new(&vdf).prove(&stop_flag);
+        let compressed = verifier
+            .compress(&solution, 1)
+            .expect("compress genuine proof");
+
+        assert!(verifier.verify_compressed(&compressed));
+        let decompressed = verifier.decompress(&compressed).expect("reconstruct output");
+        assert_eq!(decompressed.second, solution.second);
+    }
+
+    #[test]
+    fn test_compressed_rejects_wrong_prefix() {
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01];
+        let input = vec![0x02];
+
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let verifier = WesolowskiVerifier::new(&vdf);
+        let stop_flag = CancellationToken::new();
+
+        let solution = WesolowskiProver::new(&vdf).prove(&stop_flag);
+        // y = 2^(2^16) mod 257 = 1 is a single byte, so keep it whole: dropping
+        // a byte would leave an empty prefix with nothing to tamper with.
+        let mut compressed = verifier
+            .compress(&solution, 0)
+            .expect("compress genuine proof");
+
+        // Tampering with the committed prefix both re-derives a different
+        // challenge and breaks the reconstruction check, so the proof fails.
+        compressed.output_prefix[0] ^= 0xff;
+        assert!(!verifier.verify_compressed(&compressed));
+    }
+
+    #[test]
+    fn test_compressed_rejects_arbitrary_pi() {
+        // The former unsoundness: an attacker picks an arbitrary π and a
+        // matching committed output with no delay computation. Binding the
+        // challenge to the committed output instead of π must reject it.
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01];
+        let input = vec![0x02];
+
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let verifier = WesolowskiVerifier::new(&vdf);
+
+        // π = base, and a forged 4-byte prefix that does not match the output
+        // the equation actually reconstructs to.
+        let forged = CompressedSolution {
+            first: vdf.base().to_digits::<u8>(rug::integer::Order::MsfBe),
+            output_prefix: vec![0xde, 0xad, 0xbe, 0xef],
+        };
+        assert!(!verifier.verify_compressed(&forged));
+    }
+
+    #[test]
+    fn test_verify_n_wesolowski_single_segment() {
+        // A depth-1 chain whose only segment spans the full delay must accept
+        // exactly when the equivalent single proof does.
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01]; // 257
+        let input = vec![0x02];
+
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let verifier = WesolowskiVerifier::new(&vdf);
+        let stop_flag = CancellationToken::new();
+
+        let prover = WesolowskiProver::new(&vdf);
+        let solution = prover.prove(&stop_flag);
+
+        let segment = NWesolowskiSegment {
+            y: solution.second.clone(),
+            pi: solution.first.clone(),
+            iterations: vdf.iterations().clone(),
+        };
+        assert!(verifier.verify_n_wesolowski(&[segment], 1));
+    }
+
+    #[test]
+    fn test_verify_n_wesolowski_rejects_bad_shape() {
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01];
+        let input = vec![0x02];
+
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let verifier = WesolowskiVerifier::new(&vdf);
+        let stop_flag = CancellationToken::new();
+        let solution = WesolowskiProver::new(&vdf).prove(&stop_flag);
+
+        let good = NWesolowskiSegment {
+            y: solution.second.clone(),
+            pi: solution.first.clone(),
+            iterations: vdf.iterations().clone(),
+        };
+
+        // Wrong declared depth.
+        assert!(!verifier.verify_n_wesolowski(std::slice::from_ref(&good), 2));
+        // Empty chain.
+        assert!(!verifier.verify_n_wesolowski(&[], 0));
+        // Segment lengths that do not sum to T.
+        let short = NWesolowskiSegment {
+            y: solution.second.clone(),
+            pi: solution.first.clone(),
+            iterations: vdf.iterations().clone() - 1,
+        };
+        assert!(!verifier.verify_n_wesolowski(&[short], 1));
+    }
+
     #[test]
     fn test_edge_case_time_bits() {
         // Test with minimal time_bits (T = 2^1 = 2 iterations)
@@ -0,0 +1,433 @@
+//! Group abstraction for the Wesolowski verification equation.
+//!
+//! The original verifier works in `(Z/NZ)*`, which is only a group of unknown
+//! order when `N` is a trusted — or very large — RSA modulus. Chia's production
+//! VDF instead evaluates Wesolowski over the class group of an imaginary
+//! quadratic field, which has no trusted setup: the public parameter is just a
+//! fundamental discriminant `D`.
+//!
+//! [`VdfGroup`] captures the handful of operations the verifier actually needs —
+//! an identity, composition, squaring, exponentiation and a canonical byte
+//! encoding — so the verification relation `y == x^r · π^p` becomes group
+//! operations independent of the underlying representation. Two backends
+//! implement it: [`RsaGroup`] (the existing `rug::Integer` modular group) and
+//! [`ClassGroup`] (reduced binary quadratic forms `(a, b, c)` with
+//! `b² − 4ac = D`). Because `hash_to_prime` hashes the serialized `x ‖ y`, the
+//! canonical [`VdfGroup::encode`] is what pins the challenge prime to the
+//! instance regardless of backend.
+//!
+//! Only the class-group composition is non-trivial; it follows the classical
+//! Gauss composition used by chiavdf's Python reference, reducing the result to
+//! the unique reduced form in the class.
+
+/// A group the Wesolowski verification equation can be evaluated over.
+///
+/// Implementations are expected to be mathematical groups of unknown order (so
+/// that the low-order / adaptive-root assumptions hold); the verifier never
+/// relies on knowing the order.
+pub trait VdfGroup {
+    /// A group element.
+    type Element: Clone + PartialEq;
+
+    /// The group identity.
+    fn identity(&self) -> Self::Element;
+
+    /// Composes two elements, `a · b`.
+    fn compose(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    /// Squares an element, `a · a`. Provided separately so backends with a
+    /// faster doubling (e.g. NUDUPL for class groups) can specialize it.
+    fn square(&self, a: &Self::Element) -> Self::Element {
+        self.compose(a, a)
+    }
+
+    /// Computes `a^exp` by square-and-multiply over [`compose`](Self::compose)
+    /// and [`square`](Self::square).
+    fn pow(&self, a: &Self::Element, exp: &rug::Integer) -> Self::Element {
+        if exp.is_zero() {
+            return self.identity();
+        }
+        let mut acc = self.identity();
+        // Most-significant bit first so each step squares then conditionally
+        // multiplies in the base.
+        for bit in (0..exp.significant_bits()).rev() {
+            acc = self.square(&acc);
+            if exp.get_bit(bit) {
+                acc = self.compose(&acc, a);
+            }
+        }
+        acc
+    }
+
+    /// Canonical big-endian encoding of an element, used to build the
+    /// hash-to-prime transcript `x ‖ y`.
+    fn encode(&self, a: &Self::Element) -> Vec<u8>;
+}
+
+/// The original multiplicative group `(Z/NZ)*`, backed by `rug::Integer`.
+///
+/// Requires a trusted or sufficiently large RSA modulus `N`; kept as the
+/// default backend for compatibility with existing call sites.
+pub struct RsaGroup {
+    modulus: rug::Integer,
+}
+
+impl RsaGroup {
+    /// Creates the group modulo `modulus`.
+    pub fn new(modulus: rug::Integer) -> Self {
+        RsaGroup { modulus }
+    }
+}
+
+impl VdfGroup for RsaGroup {
+    type Element = rug::Integer;
+
+    fn identity(&self) -> Self::Element {
+        rug::Integer::from(1)
+    }
+
+    fn compose(&self, a: &Self::Element, b: &Self::Element) -> Self::Element {
+        let mut out = rug::Integer::from(a * b);
+        out %= &self.modulus;
+        out
+    }
+
+    fn pow(&self, a: &Self::Element, exp: &rug::Integer) -> Self::Element {
+        a.clone()
+            .pow_mod(exp, &self.modulus)
+            .expect("exponent is non-negative")
+    }
+
+    fn encode(&self, a: &Self::Element) -> Vec<u8> {
+        a.to_digits(rug::integer::Order::MsfBe)
+    }
+}
+
+/// A reduced binary quadratic form `(a, b, c)` of discriminant `D = b² − 4ac`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Form {
+    /// First coefficient `a`.
+    pub a: rug::Integer,
+    /// Middle coefficient `b`.
+    pub b: rug::Integer,
+    /// Third coefficient `c`.
+    pub c: rug::Integer,
+}
+
+/// The class group of the imaginary quadratic field with fundamental
+/// discriminant `D < 0`, realized as reduced binary quadratic forms.
+///
+/// No trusted setup is needed: `D` is public and the group order is unknown but
+/// believed hard to compute, which is exactly the assumption Wesolowski relies
+/// on.
+pub struct ClassGroup {
+    discriminant: rug::Integer,
+}
+
+impl ClassGroup {
+    /// Creates the class group of discriminant `discriminant` (must be negative
+    /// and `≡ 0` or `1 (mod 4)`).
+    pub fn new(discriminant: rug::Integer) -> Self {
+        ClassGroup { discriminant }
+    }
+
+    /// The discriminant `D` these forms share.
+    pub fn discriminant(&self) -> &rug::Integer {
+        &self.discriminant
+    }
+
+    /// Recovers `c = (b² − D) / (4a)` so the form has exactly discriminant `D`.
+    fn derive_c(&self, a: &rug::Integer, b: &rug::Integer) -> rug::Integer {
+        let mut numer = rug::Integer::from(b * b);
+        numer -= &self.discriminant;
+        let denom = rug::Integer::from(a) * 4;
+        numer / denom
+    }
+
+    /// Puts a form into normal form: `−a < b ≤ a`.
+    fn normalize(&self, mut form: Form) -> Form {
+        let two_a = rug::Integer::from(&form.a * 2);
+        if form.b > form.a || &form.b <= &rug::Integer::from(-&form.a) {
+            // r = round((a - b) / (2a)) toward -inf so the result lands in range.
+            let r = floor_div(&rug::Integer::from(&form.a - &form.b), &two_a);
+            form.b += rug::Integer::from(&two_a * &r);
+            form.c = self.derive_c(&form.a, &form.b);
+        }
+        form
+    }
+
+    /// Reduces a form to the unique reduced representative of its class.
+    fn reduce(&self, form: Form) -> Form {
+        let mut form = self.normalize(form);
+        while form.a > form.c || (form.a == form.c && form.b.is_negative()) {
+            let two_c = rug::Integer::from(&form.c * 2);
+            let s = floor_div(&rug::Integer::from(&form.c + &form.b), &two_c);
+            // (a, b, c) -> (c, -b + 2sc, c s² - b s + a)
+            let new_a = form.c.clone();
+            let new_b = rug::Integer::from(&two_c * &s) - &form.b;
+            form = Form {
+                a: new_a,
+                b: new_b,
+                c: rug::Integer::new(),
+            };
+            form.c = self.derive_c(&form.a, &form.b);
+        }
+        if form.a == form.c && form.b.is_negative() {
+            form.b = -form.b;
+            form.c = self.derive_c(&form.a, &form.b);
+        }
+        form
+    }
+}
+
+impl VdfGroup for ClassGroup {
+    type Element = Form;
+
+    fn identity(&self) -> Self::Element {
+        // Principal form: a = 1, b = D mod 2, c derived from D.
+        let a = rug::Integer::from(1);
+        let b = if self.discriminant.is_odd() {
+            rug::Integer::from(1)
+        } else {
+            rug::Integer::from(0)
+        };
+        let c = self.derive_c(&a, &b);
+        Form { a, b, c }
+    }
+
+    fn compose(&self, f1: &Self::Element, f2: &Self::Element) -> Self::Element {
+        // Classical Gauss composition (chiavdf reference): form the combined
+        // coefficients, then reduce. `c` is always recovered from the
+        // discriminant so the product is guaranteed to have discriminant D.
+        let (a1, b1, c1) = (&f1.a, &f1.b, &f1.c);
+        let (a2, b2) = (&f2.a, &f2.b);
+
+        let g = rug::Integer::from(b1 + b2) / 2; // (b1 + b2) / 2
+        let h = rug::Integer::from(b2 - b1) / 2; // (b2 - b1) / 2
+        let w = gcd3(a1, a2, &g);
+
+        let s = rug::Integer::from(a1 / &w);
+        let t = rug::Integer::from(a2 / &w);
+        let u = rug::Integer::from(&g / &w);
+
+        // Solve t·u·k ≡ h·u + s·c1 (mod s·t).
+        let st = rug::Integer::from(&s * &t);
+        let rhs1 = rug::Integer::from(&h * &u) + rug::Integer::from(&s * c1);
+        let (mu, v) = match solve_linear_congruence(&rug::Integer::from(&t * &u), &rhs1, &st) {
+            Some(pair) => pair,
+            // Degenerate inputs (non-primitive forms) collapse to the identity;
+            // this should not arise for valid VDF elements.
+            None => return self.identity(),
+        };
+
+        // Solve t·v·lambda ≡ h − t·mu (mod s).
+        let rhs2 = rug::Integer::from(&h - &rug::Integer::from(&t * &mu));
+        let lambda = match solve_linear_congruence(&rug::Integer::from(&t * &v), &rhs2, &s) {
+            Some((l, _)) => l,
+            None => return self.identity(),
+        };
+
+        let k = rug::Integer::from(&mu + &rug::Integer::from(&v * &lambda));
+        // A = s·t, B = b2 − 2·t·k. Pinning B off b2 (not g = (b1+b2)/2) matters
+        // whenever w > 1: g collapses information h carries, so a g-based B can
+        // land on the wrong residue mod 2·new_a and leave derive_c's division
+        // inexact.
+        let new_a = st;
+        let tk = rug::Integer::from(&t * &k);
+        let new_b = rug::Integer::from(b2 - rug::Integer::from(&tk * 2));
+        let new_c = self.derive_c(&new_a, &new_b);
+
+        self.reduce(Form {
+            a: new_a,
+            b: new_b,
+            c: new_c,
+        })
+    }
+
+    fn encode(&self, form: &Self::Element) -> Vec<u8> {
+        // `a` then `b`; `c` is redundant given the discriminant. Each is written
+        // as a sign byte, its magnitude and a 0xff separator (`encode_signed`).
+        let mut out = Vec::new();
+        encode_signed(&mut out, &form.a);
+        encode_signed(&mut out, &form.b);
+        out
+    }
+}
+
+/// Appends `value` to `out` as a sign byte followed by big-endian magnitude
+/// digits, so both coefficients of a form serialize unambiguously.
+fn encode_signed(out: &mut Vec<u8>, value: &rug::Integer) {
+    out.push(if value.is_negative() { 1 } else { 0 });
+    let magnitude = rug::Integer::from(value.abs_ref());
+    out.extend_from_slice(&magnitude.to_digits::<u8>(rug::integer::Order::MsfBe));
+    out.push(0xff); // separator so variable-length magnitudes cannot run together
+}
+
+/// Floor division `n / d` (rounding toward negative infinity), unlike the
+/// truncating division `rug` performs by default.
+fn floor_div(n: &rug::Integer, d: &rug::Integer) -> rug::Integer {
+    let (q, r) = rug::Integer::from(n).div_rem(d.clone());
+    if r.is_zero() || (r.is_negative() == d.is_negative()) {
+        q
+    } else {
+        q - 1
+    }
+}
+
+/// Greatest common divisor of three integers.
+fn gcd3(a: &rug::Integer, b: &rug::Integer, c: &rug::Integer) -> rug::Integer {
+    let g = rug::Integer::from(a.gcd_ref(b));
+    rug::Integer::from(g.gcd_ref(c))
+}
+
+/// Extended Euclid: returns `(g, x, y)` with `g = x·a + y·b` and `g ≥ 0`.
+fn xgcd(a: &rug::Integer, b: &rug::Integer) -> (rug::Integer, rug::Integer, rug::Integer) {
+    let mut old_r = a.clone();
+    let mut r = b.clone();
+    let mut old_s = rug::Integer::from(1);
+    let mut s = rug::Integer::from(0);
+    let mut old_t = rug::Integer::from(0);
+    let mut t = rug::Integer::from(1);
+    while !r.is_zero() {
+        let q = rug::Integer::from(&old_r / &r);
+        let new_r = rug::Integer::from(&old_r - &rug::Integer::from(&q * &r));
+        old_r = std::mem::replace(&mut r, new_r);
+        let new_s = rug::Integer::from(&old_s - &rug::Integer::from(&q * &s));
+        old_s = std::mem::replace(&mut s, new_s);
+        let new_t = rug::Integer::from(&old_t - &rug::Integer::from(&q * &t));
+        old_t = std::mem::replace(&mut t, new_t);
+    }
+    if old_r.is_negative() {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// Solves `a·x ≡ b (mod m)`, returning a representative `x` and the modulus
+/// step `m / gcd(a, m)`, or `None` when no solution exists.
+fn solve_linear_congruence(
+    a: &rug::Integer,
+    b: &rug::Integer,
+    m: &rug::Integer,
+) -> Option<(rug::Integer, rug::Integer)> {
+    let (g, x, _) = xgcd(a, m);
+    if !rug::Integer::from(b % &g).is_zero() {
+        return None;
+    }
+    let step = rug::Integer::from(m / &g);
+    let mul = rug::Integer::from(b / &g);
+    let mut sol = rug::Integer::from(&x * &mul);
+    sol %= &step;
+    if sol.is_negative() {
+        sol += &step;
+    }
+    Some((sol, step))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rsa_group_matches_pow_mod() {
+        let group = RsaGroup::new(rug::Integer::from(257));
+        let base = rug::Integer::from(3);
+        let exp = rug::Integer::from(20);
+        let got = group.pow(&base, &exp);
+        let expected = base.clone().pow_mod(&exp, &rug::Integer::from(257)).unwrap();
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn test_class_group_identity_is_neutral() {
+        // A small fundamental discriminant D ≡ 1 (mod 4).
+        let group = ClassGroup::new(rug::Integer::from(-23));
+        let id = group.identity();
+        // D = b² - 4ac holds for the identity.
+        let disc = rug::Integer::from(&id.b * &id.b) - rug::Integer::from(&id.a * &id.c) * 4;
+        assert_eq!(disc, *group.discriminant());
+
+        // Composing the identity with itself stays the identity.
+        let squared = group.square(&id);
+        assert_eq!(squared, id);
+    }
+
+    #[test]
+    fn test_class_group_compose_preserves_discriminant() {
+        let group = ClassGroup::new(rug::Integer::from(-23));
+        let g = group.identity();
+        // Exponentiating the identity (repeated square/compose) stays on D.
+        let e = group.pow(&g, &rug::Integer::from(7));
+        let disc = rug::Integer::from(&e.b * &e.b) - rug::Integer::from(&e.a * &e.c) * 4;
+        assert_eq!(disc, *group.discriminant());
+    }
+
+    #[test]
+    fn test_encode_is_deterministic() {
+        let group = ClassGroup::new(rug::Integer::from(-23));
+        let id = group.identity();
+        assert_eq!(group.encode(&id), group.encode(&id));
+    }
+
+    /// `(2, 1, 3)` generates the order-3 class group of `D = -23`, so its
+    /// square is its own inverse `(2, -1, 3)` — a known-correct answer the
+    /// old `g − k·t` formula got wrong for every non-identity, non-square pair.
+    #[test]
+    fn test_class_group_compose_matches_known_answer() {
+        let group = ClassGroup::new(rug::Integer::from(-23));
+        let f = Form {
+            a: rug::Integer::from(2),
+            b: rug::Integer::from(1),
+            c: rug::Integer::from(3),
+        };
+        let squared = group.compose(&f, &f);
+        let expected = Form {
+            a: rug::Integer::from(2),
+            b: rug::Integer::from(-1),
+            c: rug::Integer::from(3),
+        };
+        assert_eq!(squared, expected);
+    }
+
+    /// Every reduced form of `D = -23` composed with every other must land
+    /// back on a reduced form of the same discriminant: the group is closed
+    /// and `compose` must not produce a form off the discriminant or outside
+    /// the enumerated class set.
+    #[test]
+    fn test_class_group_compose_is_closed_over_reduced_forms() {
+        let d = rug::Integer::from(-23);
+        let group = ClassGroup::new(d.clone());
+        // The full set of reduced forms of discriminant -23 (class number 3).
+        let forms = [
+            Form {
+                a: rug::Integer::from(1),
+                b: rug::Integer::from(1),
+                c: rug::Integer::from(6),
+            },
+            Form {
+                a: rug::Integer::from(2),
+                b: rug::Integer::from(1),
+                c: rug::Integer::from(3),
+            },
+            Form {
+                a: rug::Integer::from(2),
+                b: rug::Integer::from(-1),
+                c: rug::Integer::from(3),
+            },
+        ];
+
+        for f1 in &forms {
+            for f2 in &forms {
+                let composed = group.compose(f1, f2);
+                let disc = rug::Integer::from(&composed.b * &composed.b)
+                    - rug::Integer::from(&composed.a * &composed.c) * 4;
+                assert_eq!(disc, d, "compose({f1:?}, {f2:?}) left the discriminant");
+                assert!(
+                    forms.contains(&composed),
+                    "compose({f1:?}, {f2:?}) = {composed:?} is not a reduced form of D"
+                );
+            }
+        }
+    }
+}
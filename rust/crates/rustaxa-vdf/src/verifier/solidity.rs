@@ -0,0 +1,514 @@
+use crate::vdf::{Solution, WesolowskiVdf};
+
+/// On-chain (EVM) rendering of the Wesolowski verifier.
+///
+/// Taraxa is EVM-compatible, so a VDF proof produced off-chain in Rust/C++ can
+/// be re-checked by an Ethereum-style contract. Following the
+/// halo2-solidity-verifier layout, the templated contract body is kept separate
+/// from the instance/parameter data: [`render_verifier`] emits a standalone
+/// contract pinned to a concrete modulus `N` and base `g`, while
+/// [`encode_calldata`] ABI-encodes a concrete [`Solution`] and time parameter
+/// `T` into the bytes that contract's `verify` entry point accepts.
+///
+/// The contract recomputes the challenge prime `l = hash_to_prime(g ‖ y)` (`T`
+/// is not hashed; it enters only through `r`), then `r = 2^T mod l`, and
+/// accepts iff `π^l · g^r ≡ y (mod N)`, performing the
+/// big-integer modmuls through the `modexp` precompile at address `0x05`.
+pub struct SolidityVerifier<'a> {
+    vdf: &'a WesolowskiVdf,
+}
+
+impl<'a> SolidityVerifier<'a> {
+    pub fn new(vdf: &'a WesolowskiVdf) -> Self {
+        SolidityVerifier { vdf }
+    }
+
+    /// Renders a standalone Solidity contract that re-checks a [`Solution`]
+    /// on-chain for this VDF's parameters.
+    ///
+    /// The generated contract inlines the modulus `N` and base `g` as the
+    /// instance data and exposes `verify(bytes pi, bytes y, uint256 T)`, which
+    /// returns `true` exactly when the Rust verifier would.
+    pub fn render_verifier(&self) -> String {
+        let modulus = hex_words(self.vdf.modulus());
+        let base = hex_words(self.vdf.base());
+        let modulus_bytes = byte_len(self.vdf.modulus());
+        let modulus_bits = self.vdf.modulus().significant_bits();
+
+        render_template(&base, &modulus, modulus_bytes, modulus_bits, self.prime_bits())
+    }
+
+    /// The challenge-prime bit-length the rendered contract must reproduce.
+    ///
+    /// The Rust hash-to-prime forces bit `prime_bits - 1` of every candidate, so
+    /// the emitted prime has exactly `prime_bits` significant bits; probing a
+    /// single transcript recovers it without threading the security parameter
+    /// through the renderer. The on-chain path keeps `l` in a `uint256`, so this
+    /// must not exceed 256 (i.e. `lambda <= 128` under the default `2·lambda`).
+    fn prime_bits(&self) -> u32 {
+        self.vdf
+            .hash_to_prime(&rug::Integer::from(1))
+            .map(|l| l.significant_bits())
+            .unwrap_or(256)
+    }
+}
+
+/// ABI-encodes `(proof, output, T)` into the calldata accepted by the generated
+/// contract's `verify(bytes,bytes,uint256)` entry point.
+///
+/// The layout is the standard Solidity ABI head/tail encoding: a 4-byte
+/// selector, the two dynamic `bytes` offsets, the `uint256` time parameter, and
+/// the length-prefixed, 32-byte-padded tails for `pi` and `y`.
+pub fn encode_calldata(solution: &Solution, iterations: &rug::Integer) -> Vec<u8> {
+    // keccak256("verify(bytes,bytes,uint256)")[..4]
+    const SELECTOR: [u8; 4] = [0x4d, 0xf9, 0x0a, 0x0a];
+
+    let mut out = Vec::with_capacity(4 + 32 * 3);
+    out.extend_from_slice(&SELECTOR);
+
+    // Head: offset(pi), offset(y), T. Offsets are relative to the start of the
+    // argument block (i.e. exclude the selector).
+    let head_len = 32 * 3;
+    let pi_offset = head_len;
+    let pi_tail = encode_bytes(&solution.first);
+    let y_offset = head_len + pi_tail.len();
+    let y_tail = encode_bytes(&solution.second);
+
+    out.extend_from_slice(&left_pad_u256(pi_offset as u64));
+    out.extend_from_slice(&left_pad_u256(y_offset as u64));
+    out.extend_from_slice(&uint256_from_integer(iterations));
+
+    // Tail.
+    out.extend_from_slice(&pi_tail);
+    out.extend_from_slice(&y_tail);
+    out
+}
+
+/// Encodes a dynamic `bytes` value: a 32-byte length word followed by the data
+/// right-padded to a 32-byte boundary.
+fn encode_bytes(data: &[u8]) -> Vec<u8> {
+    let padded_len = data.len().div_ceil(32) * 32;
+    let mut out = Vec::with_capacity(32 + padded_len);
+    out.extend_from_slice(&left_pad_u256(data.len() as u64));
+    out.extend_from_slice(data);
+    out.resize(32 + padded_len, 0);
+    out
+}
+
+/// Left-pads a small integer into a 32-byte big-endian word.
+fn left_pad_u256(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Encodes an arbitrary-precision integer into a 32-byte big-endian word,
+/// panicking only if it would not fit (callers pass `T`, which is bounded).
+fn uint256_from_integer(value: &rug::Integer) -> [u8; 32] {
+    let bytes = value.to_digits::<u8>(rug::integer::Order::MsfBe);
+    let mut word = [0u8; 32];
+    let start = 32 - bytes.len();
+    word[start..].copy_from_slice(&bytes);
+    word
+}
+
+/// Number of bytes needed to represent `value`.
+fn byte_len(value: &rug::Integer) -> u32 {
+    value.significant_bits().div_ceil(8).max(1)
+}
+
+/// Renders a big integer as a `0x`-prefixed Solidity hex literal.
+fn hex_words(value: &rug::Integer) -> String {
+    let bytes = value.to_digits::<u8>(rug::integer::Order::MsfBe);
+    let mut s = String::from("0x");
+    if bytes.is_empty() {
+        s.push_str("00");
+    } else {
+        for b in bytes {
+            s.push_str(&format!("{:02x}", b));
+        }
+    }
+    s
+}
+
+/// The templated verifier body. The parameters (`g`, `N`) are interpolated as
+/// instance data, mirroring the halo2-solidity-verifier separation of template
+/// from instances.
+fn render_template(
+    base: &str,
+    modulus: &str,
+    modulus_bytes: u32,
+    modulus_bits: u32,
+    prime_bits: u32,
+) -> String {
+    format!(
+        r#"// SPDX-License-Identifier: MIT
+// Auto-generated Wesolowski VDF verifier. Do not edit by hand.
+pragma solidity ^0.8.19;
+
+contract WesolowskiVdfVerifier {{
+    // Instance data: modulus N and base g (big-endian).
+    bytes constant N = hex"{modulus_hex}";
+    bytes constant G = hex"{base_hex}";
+    uint256 constant N_BYTES = {modulus_bytes};
+    // Bit-length of N, used to pack the (g || y) transcript exactly as the Rust
+    // verifier does: the hash input is the integer (g << N_BITS) + y.
+    uint256 constant N_BITS = {modulus_bits};
+    // Challenge-prime width. The hash sets bit PRIME_BITS-1 of every candidate;
+    // the on-chain path keeps l in a uint256, so PRIME_BITS must be <= 256.
+    uint256 constant PRIME_BITS = {prime_bits};
+    uint256 constant PRIME_BYTES = ({prime_bits} + 7) / 8;
+
+    /// Accepts iff pi^l * g^r == y (mod N), with l = hashToPrime(g, y) and
+    /// r = 2^T mod l. The challenge is bound to the true output y exactly as in
+    /// the Rust verifier; T enters only through the exponent r.
+    function verify(bytes calldata pi, bytes calldata y, uint256 T)
+        external
+        view
+        returns (bool)
+    {{
+        uint256 l = hashToPrime(y);
+        uint256 r = powmodU(2, T, l);
+        bytes memory lhs = modmul(
+            modexp(pi, abi.encodePacked(l), N),
+            modexp(G, abi.encodePacked(r), N),
+            N
+        );
+        return eqMod(lhs, y);
+    }}
+
+    /// Recomputes the challenge prime exactly as the Rust Keccak hash-to-prime
+    /// does: a Keccak-256 counter-mode digest stream over the packed transcript
+    /// be((g << N_BITS) + y), truncated to PRIME_BYTES, with bit PRIME_BITS-1
+    /// forced set and the low bit forced odd, then nextprime by stepping +2.
+    function hashToPrime(bytes calldata y) internal view returns (uint256) {{
+        bytes memory input = transcript(y);
+
+        // Counter-mode XOF: keccak256(input || be32(counter)) blocks until
+        // PRIME_BYTES bytes are available.
+        bytes memory stream = new bytes(((PRIME_BYTES + 31) / 32) * 32);
+        uint32 counter = 0;
+        for (uint256 off = 0; off < stream.length; off += 32) {{
+            bytes32 block_ = keccak256(abi.encodePacked(input, counter));
+            assembly {{
+                mstore(add(add(stream, 0x20), off), block_)
+            }}
+            counter += 1;
+        }}
+
+        // Interpret the first PRIME_BYTES bytes as a big-endian integer.
+        uint256 candidate = 0;
+        for (uint256 i = 0; i < PRIME_BYTES; i++) {{
+            candidate = (candidate << 8) | uint256(uint8(stream[i]));
+        }}
+        // Force the exact width and oddness, matching the Rust construction.
+        candidate |= (uint256(1) << (PRIME_BITS - 1));
+        candidate |= 1;
+
+        while (!isProbablePrime(candidate)) {{
+            candidate += 2;
+        }}
+        return candidate;
+    }}
+
+    /// Builds be((g << N_BITS) + y), the byte-for-byte transcript the Rust
+    /// verifier feeds to hash-to-prime (its `to_digits` drops leading zeros).
+    function transcript(bytes calldata y) internal pure returns (bytes memory) {{
+        bytes memory packed = bigAdd(bigShlBits(G, N_BITS), y);
+        return stripLeadingZeros(packed);
+    }}
+
+    // --- big-integer helpers over the modexp precompile (address 0x05) ---
+
+    function modexp(bytes memory base, bytes memory exp, bytes memory mod)
+        internal
+        view
+        returns (bytes memory)
+    {{
+        bytes memory input = abi.encodePacked(
+            uint256(base.length), uint256(exp.length), uint256(mod.length),
+            base, exp, mod
+        );
+        bytes memory output = new bytes(mod.length);
+        assembly {{
+            if iszero(staticcall(gas(), 0x05, add(input, 0x20), mload(input), add(output, 0x20), mload(mod))) {{
+                revert(0, 0)
+            }}
+        }}
+        return output;
+    }}
+
+    /// (a * b) mod N for big-endian byte integers, via the modexp-friendly
+    /// identity 2·a·b = (a+b)^2 - a^2 - b^2 (mod N). The three squarings go
+    /// through the modexp precompile; the final division by two is the modular
+    /// halving (N is odd). Inputs are assumed already reduced mod N.
+    function modmul(bytes memory a, bytes memory b, bytes memory mod)
+        internal
+        view
+        returns (bytes memory)
+    {{
+        bytes memory two = abi.encodePacked(uint256(2));
+        bytes memory s = modexp(bigAdd(a, b), two, mod);   // (a+b)^2 mod N
+        bytes memory a2 = modexp(a, two, mod);             // a^2 mod N
+        bytes memory b2 = modexp(b, two, mod);             // b^2 mod N
+        bytes memory t = subMod(subMod(s, a2, mod), b2, mod); // 2ab mod N
+        return halveMod(t, mod);                            // ab mod N
+    }}
+
+    /// (x - y) mod m for reduced x, y in [0, m).
+    function subMod(bytes memory x, bytes memory y, bytes memory m)
+        internal
+        pure
+        returns (bytes memory)
+    {{
+        if (bigCmp(x, y) >= 0) {{
+            return bigSub(x, y);
+        }}
+        return bigSub(bigAdd(x, m), y);
+    }}
+
+    /// x / 2 mod m for odd m and reduced x: x>>1 when even, (x+m)>>1 when odd.
+    function halveMod(bytes memory x, bytes memory m)
+        internal
+        pure
+        returns (bytes memory)
+    {{
+        if ((uint8(x[x.length - 1]) & 1) == 0) {{
+            return bigShr1(x);
+        }}
+        return bigShr1(bigAdd(x, m));
+    }}
+
+    /// Extra Miller-Rabin rounds beyond the fixed 12-base set, using bases
+    /// derived from the candidate itself (see `deriveBase`). The fixed bases
+    /// alone are NOT a proven deterministic test at the sizes this contract
+    /// checks: `deterministic_witnesses` on the Rust side only trusts those
+    /// twelve bases for n < 3.317e24 (~81 bits), and with the default
+    /// `lambda = 128` the challenge prime PRIME_BITS = 2*lambda is ~256 bits,
+    /// where the Rust verifier falls back to Baillie-PSW instead. A composite
+    /// crafted to survive a small set of FIXED public bases is a known,
+    /// practical construction (Arnault-style pseudoprimes exist for any fixed
+    /// base set), so the 12 fixed rounds contribute nothing to the soundness
+    /// bound against an adversarial candidate -- only the derived rounds do.
+    /// Deriving each extra base from `n` via keccak256 denies an attacker the
+    /// ability to pick `n` against bases fixed in advance, so the 52 derived
+    /// rounds alone bound the false-accept probability by the standard
+    /// Miller-Rabin worst case 4^-52 = 2^-104.
+    uint256 constant EXTRA_MR_ROUNDS = 52;
+
+    /// Miller-Rabin over a uint256 candidate: the fixed 12-base set plus
+    /// `EXTRA_MR_ROUNDS` bases derived from the candidate (see
+    /// `EXTRA_MR_ROUNDS`'s doc for why). l < 2^256, so `mulmod` reductions are
+    /// exact.
+    function isProbablePrime(uint256 n) internal pure returns (bool) {{
+        if (n < 2) return false;
+        uint8[12] memory bases = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+        for (uint256 i = 0; i < bases.length; i++) {{
+            uint256 a = bases[i];
+            if (n == a) return true;
+            if (n % a == 0) return false;
+        }}
+        uint256 d = n - 1;
+        uint256 s = 0;
+        while (d & 1 == 0) {{ d >>= 1; s += 1; }}
+        for (uint256 i = 0; i < bases.length; i++) {{
+            if (!millerRabinRound(n, bases[i], d, s)) return false;
+        }}
+        for (uint256 i = 0; i < EXTRA_MR_ROUNDS; i++) {{
+            if (!millerRabinRound(n, deriveBase(n, i), d, s)) return false;
+        }}
+        return true;
+    }}
+
+    /// Derives the `index`-th extra Miller-Rabin base for candidate `n` as
+    /// `2 + keccak256(n, index) mod (n - 3)`, landing it in `[2, n - 2]`. `n`
+    /// is always a PRIME_BITS-wide odd candidate, so `n - 3` never underflows.
+    function deriveBase(uint256 n, uint256 index) internal pure returns (uint256) {{
+        uint256 h = uint256(keccak256(abi.encodePacked(n, index)));
+        return 2 + (h % (n - 3));
+    }}
+
+    function millerRabinRound(uint256 n, uint256 a, uint256 d, uint256 s)
+        internal
+        pure
+        returns (bool)
+    {{
+        uint256 x = powmodU(a, d, n);
+        if (x == 1 || x == n - 1) return true;
+        for (uint256 r = 1; r < s; r++) {{
+            x = mulmod(x, x, n);
+            if (x == n - 1) return true;
+        }}
+        return false;
+    }}
+
+    /// base^exp mod m for m < 2^256 via square-and-multiply with `mulmod`.
+    function powmodU(uint256 base, uint256 exp, uint256 m)
+        internal
+        pure
+        returns (uint256)
+    {{
+        if (m == 1) return 0;
+        uint256 result = 1;
+        base %= m;
+        while (exp > 0) {{
+            if (exp & 1 == 1) result = mulmod(result, base, m);
+            base = mulmod(base, base, m);
+            exp >>= 1;
+        }}
+        return result;
+    }}
+
+    // --- arbitrary-length big-endian byte-integer arithmetic ---
+
+    function bigCmp(bytes memory a, bytes memory b) internal pure returns (int256) {{
+        bytes memory x = stripLeadingZeros(a);
+        bytes memory y = stripLeadingZeros(b);
+        if (x.length != y.length) return x.length < y.length ? -int256(1) : int256(1);
+        for (uint256 i = 0; i < x.length; i++) {{
+            if (x[i] != y[i]) return uint8(x[i]) < uint8(y[i]) ? -int256(1) : int256(1);
+        }}
+        return 0;
+    }}
+
+    function bigAdd(bytes memory a, bytes memory b) internal pure returns (bytes memory) {{
+        uint256 n = a.length > b.length ? a.length : b.length;
+        bytes memory out = new bytes(n + 1);
+        uint256 carry = 0;
+        for (uint256 i = 0; i < n; i++) {{
+            uint256 av = i < a.length ? uint8(a[a.length - 1 - i]) : 0;
+            uint256 bv = i < b.length ? uint8(b[b.length - 1 - i]) : 0;
+            uint256 sum = av + bv + carry;
+            out[out.length - 1 - i] = bytes1(uint8(sum & 0xff));
+            carry = sum >> 8;
+        }}
+        out[0] = bytes1(uint8(carry));
+        return stripLeadingZeros(out);
+    }}
+
+    /// a - b for a >= b.
+    function bigSub(bytes memory a, bytes memory b) internal pure returns (bytes memory) {{
+        bytes memory out = new bytes(a.length);
+        int256 borrow = 0;
+        for (uint256 i = 0; i < a.length; i++) {{
+            int256 av = int256(uint256(uint8(a[a.length - 1 - i])));
+            int256 bv = i < b.length ? int256(uint256(uint8(b[b.length - 1 - i]))) : int256(0);
+            int256 diff = av - bv - borrow;
+            if (diff < 0) {{ diff += 256; borrow = 1; }} else {{ borrow = 0; }}
+            out[out.length - 1 - i] = bytes1(uint8(uint256(diff)));
+        }}
+        return stripLeadingZeros(out);
+    }}
+
+    /// Left shift by `bits` bits.
+    function bigShlBits(bytes memory a, uint256 bits) internal pure returns (bytes memory) {{
+        uint256 byteShift = bits / 8;
+        uint256 bitShift = bits % 8;
+        bytes memory shifted = new bytes(a.length + byteShift + 1);
+        uint256 carry = 0;
+        for (uint256 i = 0; i < a.length; i++) {{
+            uint256 v = (uint256(uint8(a[a.length - 1 - i])) << bitShift) | carry;
+            shifted[shifted.length - 1 - byteShift - i] = bytes1(uint8(v & 0xff));
+            carry = v >> 8;
+        }}
+        shifted[shifted.length - 1 - byteShift - a.length] = bytes1(uint8(carry));
+        return stripLeadingZeros(shifted);
+    }}
+
+    /// Right shift by one bit.
+    function bigShr1(bytes memory a) internal pure returns (bytes memory) {{
+        bytes memory out = new bytes(a.length);
+        uint256 carry = 0;
+        for (uint256 i = 0; i < a.length; i++) {{
+            uint256 v = uint256(uint8(a[i]));
+            out[i] = bytes1(uint8((v >> 1) | (carry << 7)));
+            carry = v & 1;
+        }}
+        return stripLeadingZeros(out);
+    }}
+
+    function stripLeadingZeros(bytes memory a) internal pure returns (bytes memory) {{
+        uint256 start = 0;
+        while (start < a.length && a[start] == 0) start += 1;
+        if (start == a.length) return hex"00";
+        bytes memory out = new bytes(a.length - start);
+        for (uint256 i = 0; i < out.length; i++) out[i] = a[start + i];
+        return out;
+    }}
+
+    /// Numeric equality of two big-endian byte integers, ignoring leading zeros.
+    function eqMod(bytes memory a, bytes memory b) internal pure returns (bool) {{
+        return bigCmp(a, b) == 0;
+    }}
+}}
+"#,
+        modulus_hex = modulus.trim_start_matches("0x"),
+        base_hex = base.trim_start_matches("0x"),
+        modulus_bytes = modulus_bytes,
+        modulus_bits = modulus_bits,
+        prime_bits = prime_bits,
+    )
+}
+
+// NOTE: there is no solc/forge/hardhat toolchain (or network access to fetch
+// one) available wherever these tests run, and the repo has no existing
+// `.sol`/Foundry project to extend, so the generated contract has never
+// actually been compiled or executed against the EVM by this test suite —
+// only string-rendered and pattern-matched, same as the pre-existing tests
+// below. That gap (no solc-compiled, EVM-executed coverage of `verify`,
+// including forged-prime and truncated-calldata cases) is real and still
+// open; it should be closed with a Foundry suite once that toolchain is
+// available, rather than with unexecutable scaffolding checked in now.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_verifier_contains_instances() {
+        let vdf = WesolowskiVdf::new(128, 4, vec![0x02], vec![0x01, 0x01]);
+        let verifier = SolidityVerifier::new(&vdf);
+        let contract = verifier.render_verifier();
+
+        assert!(contract.contains("contract WesolowskiVdfVerifier"));
+        assert!(contract.contains("function verify(bytes calldata pi"));
+        // Modexp precompile address must be referenced.
+        assert!(contract.contains("0x05"));
+    }
+
+    #[test]
+    fn test_is_probable_prime_runs_derived_bases_past_the_fixed_set() {
+        let vdf = WesolowskiVdf::new(128, 4, vec![0x02], vec![0x01, 0x01]);
+        let verifier = SolidityVerifier::new(&vdf);
+        let contract = verifier.render_verifier();
+
+        // The fixed 12-base set alone is not a proven test at the ~256-bit
+        // sizes this contract checks; `isProbablePrime` must also run
+        // candidate-derived rounds rather than only the fixed bases.
+        assert!(contract.contains("uint256 constant EXTRA_MR_ROUNDS = 52;"));
+        assert!(contract.contains("function deriveBase(uint256 n, uint256 index)"));
+        assert!(contract.contains("keccak256(abi.encodePacked(n, index))"));
+        assert!(contract.contains("for (uint256 i = 0; i < EXTRA_MR_ROUNDS; i++)"));
+        assert!(contract.contains("deriveBase(n, i)"));
+    }
+
+    #[test]
+    fn test_encode_calldata_layout() {
+        let solution = Solution {
+            first: vec![0xaa, 0xbb],
+            second: vec![0xcc],
+        };
+        let t = rug::Integer::from(16);
+        let calldata = encode_calldata(&solution, &t);
+
+        // selector + 3 head words + pi tail (len word + padded) + y tail.
+        assert_eq!(calldata.len(), 4 + 32 * 3 + 64 + 64);
+        // T is encoded in the third head word.
+        assert_eq!(calldata[4 + 32 * 3 - 1], 16);
+    }
+
+    #[test]
+    fn test_hex_words_big_endian() {
+        let value = rug::Integer::from(0x0101);
+        assert_eq!(hex_words(&value), "0x0101");
+    }
+}
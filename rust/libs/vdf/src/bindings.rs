@@ -1,6 +1,15 @@
-use crate::prover::{CancellationToken, WesolowskiProver};
+use crate::prover::{CancellationToken, JobId, ProverPool, WesolowskiProver};
 use crate::vdf::{Solution, WesolowskiVdf};
-use crate::verifier::WesolowskiVerifier;
+use crate::verifier::{self, WesolowskiVerifier};
+
+// Accumulator for batch verification. cxx cannot pass a slice of opaque
+// references, so the C++ side pushes `(vdf, solution)` pairs one at a time and
+// then calls `run`.
+#[derive(Default)]
+pub struct BatchVerifier {
+    vdfs: Vec<WesolowskiVdf>,
+    solutions: Vec<Solution>,
+}
 
 #[cxx::bridge]
 mod ffi {
@@ -21,9 +30,6 @@ mod ffi {
         fn make_solution(proof: &[u8], output: &[u8]) -> Box<Solution>;
 
         fn make_cancellation_token() -> Box<CancellationToken>;
-        unsafe fn make_cancellation_token_with_atomic(
-            atomic_ptr: *const bool,
-        ) -> Box<CancellationToken>;
         fn cancellation_token_cancel(token: &CancellationToken);
 
         fn prove(vdf: &WesolowskiVdf, cancelled: &CancellationToken) -> Box<Solution>;
@@ -31,6 +37,92 @@ mod ffi {
 
         fn solution_get_proof(solution: &Solution) -> &[u8];
         fn solution_get_output(solution: &Solution) -> &[u8];
+
+        type ProverPool;
+        fn make_prover_pool(num_threads: usize) -> Box<ProverPool>;
+        fn prover_pool_submit(
+            pool: &ProverPool,
+            lambda: u32,
+            time_bits: u32,
+            input: &[u8],
+            modulus: &[u8],
+        ) -> u64;
+        fn prover_pool_poll(pool: &ProverPool, id: u64) -> Box<Solution>;
+        fn prover_pool_cancel(pool: &ProverPool, id: u64);
+
+        type BatchVerifier;
+        fn make_batch_verifier() -> Box<BatchVerifier>;
+        fn push_pair(
+            self: &mut BatchVerifier,
+            lambda: u32,
+            time_bits: u32,
+            input: &[u8],
+            modulus: &[u8],
+            proof: &[u8],
+            output: &[u8],
+        );
+        fn run(self: &BatchVerifier) -> bool;
+    }
+}
+
+pub fn make_prover_pool(num_threads: usize) -> Box<ProverPool> {
+    Box::new(ProverPool::new(num_threads))
+}
+
+pub fn prover_pool_submit(
+    pool: &ProverPool,
+    lambda: u32,
+    time_bits: u32,
+    input: &[u8],
+    modulus: &[u8],
+) -> JobId {
+    pool.submit(WesolowskiVdf::new(
+        lambda,
+        time_bits,
+        input.to_vec(),
+        modulus.to_vec(),
+    ))
+}
+
+pub fn prover_pool_poll(pool: &ProverPool, id: JobId) -> Box<Solution> {
+    // An empty solution signals "not ready yet" to the C++ caller, matching the
+    // empty-on-cancellation convention used by `prove`.
+    Box::new(pool.poll(id).unwrap_or(Solution {
+        first: vec![],
+        second: vec![],
+    }))
+}
+
+pub fn prover_pool_cancel(pool: &ProverPool, id: JobId) {
+    pool.cancel(id);
+}
+
+pub fn make_batch_verifier() -> Box<BatchVerifier> {
+    Box::new(BatchVerifier::default())
+}
+
+impl BatchVerifier {
+    fn push_pair(
+        &mut self,
+        lambda: u32,
+        time_bits: u32,
+        input: &[u8],
+        modulus: &[u8],
+        proof: &[u8],
+        output: &[u8],
+    ) {
+        self.vdfs
+            .push(WesolowskiVdf::new(lambda, time_bits, input.to_vec(), modulus.to_vec()));
+        self.solutions.push(Solution {
+            first: proof.to_vec(),
+            second: output.to_vec(),
+        });
+    }
+
+    fn run(&self) -> bool {
+        let pairs: Vec<(&WesolowskiVdf, &Solution)> =
+            self.vdfs.iter().zip(self.solutions.iter()).collect();
+        verifier::verify_batch(&pairs)
     }
 }
 
@@ -57,10 +149,6 @@ pub fn make_cancellation_token() -> Box<CancellationToken> {
     Box::new(CancellationToken::new())
 }
 
-pub fn make_cancellation_token_with_atomic(atomic_ptr: *const bool) -> Box<CancellationToken> {
-    Box::new(CancellationToken::from_atomic_ptr(atomic_ptr))
-}
-
 pub fn cancellation_token_cancel(token: &CancellationToken) {
     token.cancel();
 }
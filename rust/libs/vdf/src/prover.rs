@@ -1,6 +1,48 @@
 use crate::vdf::{Solution, WesolowskiVdf};
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use crate::verifier::NWesolowskiSegment;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::thread::JoinHandle;
+
+/// Observer notified of proving progress.
+///
+/// The prover calls [`on_progress`](ProgressSink::on_progress) at the same
+/// cadence it polls for cancellation — once every `check_interval` squarings —
+/// with the squarings done so far `i` and the delay `total`. Proving runs two
+/// passes of `total` squarings each, so `i` keeps counting through the second
+/// pass, up to `2·total`. It lets a caller (a tokio task, or the cxx bridge
+/// driving the node) surface a live percentage while a long proof runs.
+pub trait ProgressSink {
+    fn on_progress(&self, i: u64, total: u64);
+}
+
+/// A future that defers to the executor exactly once before resolving, giving
+/// [`prove_async`](WesolowskiProver::prove_async) a cooperative yield point
+/// without pulling in an async runtime.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+fn yield_once() -> YieldOnce {
+    YieldOnce(false)
+}
 
 pub struct WesolowskiProver<'a> {
     vdf: &'a WesolowskiVdf,
@@ -18,6 +60,26 @@ impl<'a> WesolowskiProver<'a> {
         *value %= modulus;
     }
 
+    /// One step of the proof long-division accumulator shared by the blocking
+    /// and async paths: `pi = pi^2 mod N`, double the running remainder `r`, and
+    /// fold in a `base` factor whenever `r` overflows the challenge prime `p`.
+    #[inline]
+    fn pi_step(
+        pi: &mut rug::Integer,
+        r: &mut rug::Integer,
+        p: &rug::Integer,
+        base: &rug::Integer,
+        modulus: &rug::Integer,
+    ) {
+        Self::square_mod(pi, modulus);
+        *r <<= 1;
+        if *r >= *p {
+            *r -= p;
+            *pi *= base;
+            *pi %= modulus;
+        }
+    }
+
     // Wesolowski prover
     pub fn prove(&self, cancelled: &CancellationToken) -> Solution {
         // Get puzzle parameters
@@ -35,12 +97,33 @@ impl<'a> WesolowskiProver<'a> {
             }
         };
 
-        // Compute y = x^(2^T) mod N by repeatedly squaring
-        let mut y = base.clone();
-
         // Optimize cancellation check frequency based on iteration count
         let check_interval = (iterations_u64 / 100).clamp(1, 10000);
 
+        // GMP-free path: an odd modulus that fits a fixed width runs both hot
+        // loops on stack-allocated limbs, removing per-iteration heap traffic.
+        // The result is converted back to bytes identically to the paths below.
+        #[cfg(feature = "fixed-bigint")]
+        if !modulus.is_even() {
+            let bits = modulus.significant_bits();
+            if bits <= 2048 {
+                return self.prove_fixed::<32>(iterations_u64, check_interval, cancelled);
+            } else if bits <= 4096 {
+                return self.prove_fixed::<64>(iterations_u64, check_interval, cancelled);
+            }
+        }
+
+        // Fast path: an odd modulus lets both hot loops run in Montgomery form,
+        // replacing the per-step `% N` division with REDC shifts/adds. The
+        // output is converted back out, so the encoded `Solution` is identical
+        // to the remainder-based path below.
+        if let Some(mont) = self.vdf.montgomery() {
+            return self.prove_montgomery(mont, iterations_u64, check_interval, cancelled);
+        }
+
+        // Compute y = x^(2^T) mod N by repeatedly squaring
+        let mut y = base.clone();
+
         for i in 1..=iterations_u64 {
             // Check cancellation at optimal intervals
             if i % check_interval == 0 && cancelled.is_cancelled() {
@@ -86,18 +169,8 @@ impl<'a> WesolowskiProver<'a> {
                 };
             }
 
-            // pi = pi^2 mod N - use helper method for consistency
-            Self::square_mod(&mut pi, modulus);
-
-            // r = r * 2 = left shift by 1
-            r <<= 1;
-
-            // If r >= p, then r = r - p and pi = pi * x mod N
-            if r >= p {
-                r -= &p;
-                pi *= base;
-                pi %= modulus;
-            }
+            // Advance the shared long-division accumulator one step.
+            Self::pi_step(&mut pi, &mut r, &p, base, modulus);
         }
 
         // Convert pi and y to byte vectors
@@ -111,6 +184,454 @@ impl<'a> WesolowskiProver<'a> {
         }
     }
 
+    /// Generates the proof with the `pi` accumulation parallelized across
+    /// `threads` worker threads, returning the identical [`Solution`] as
+    /// [`prove`](Self::prove).
+    ///
+    /// The forward pass that computes `y = x^(2^T) mod N` is inherently
+    /// sequential, but it squares through exactly the intermediate values
+    /// `B_j = x^(2^(jL)) mod N` (with `L = T/k`) that the second pass needs, so
+    /// they are saved as checkpoints at zero extra cost. The proof exponent
+    /// `q = ⌊2^T / p⌋` is then split into `k` chunks `q = Σ_j 2^(jL)·Q_j`, giving
+    /// `pi = Π_j B_j^(Q_j)`. Each `B_j^(Q_j)` is an independent `~L`-step
+    /// exponentiation dispatched to its own thread, and the `k` partials are
+    /// combined by modular multiplication — cutting the second pass's critical
+    /// path from `T` to `~T/k`.
+    ///
+    /// Cancellation is honoured during the forward pass and once per chunk; a
+    /// cancelled proof returns an empty [`Solution`] like [`prove`](Self::prove).
+    pub fn prove_parallel(&self, threads: usize, cancelled: &CancellationToken) -> Solution {
+        let modulus = self.vdf.modulus();
+        let base = self.vdf.base();
+
+        let empty = || Solution {
+            first: vec![],
+            second: vec![],
+        };
+
+        // The checkpoint scheme needs a word-sized delay; fall back to the
+        // sequential path for oversized or trivial delays.
+        let total = match self.vdf.iterations().to_u64() {
+            Some(t) if t > 0 => t,
+            _ => return self.prove(cancelled),
+        };
+        let k = threads.max(1).min(total as usize) as u64;
+        let chunk = total / k; // L = T/k; the last chunk absorbs the remainder.
+
+        let mont = self.vdf.montgomery();
+        let check_interval = (total / 100).clamp(1, 10000);
+
+        // Forward pass: square through the delay, snapshotting B_j = x^(2^(jL))
+        // at every chunk boundary. Montgomery form when the modulus is odd.
+        let mut checkpoints: Vec<rug::Integer> = Vec::with_capacity(k as usize);
+        let y_normal = if let Some(mont) = mont {
+            let mut y = mont.to_form(base, modulus);
+            checkpoints.push(base.clone()); // B_0 = x
+            for i in 1..=total {
+                if i % check_interval == 0 && cancelled.is_cancelled() {
+                    return empty();
+                }
+                y = mont.mul(&y, &y, modulus);
+                if i % chunk == 0 && (i / chunk) < k {
+                    checkpoints.push(mont.from_form(y.clone(), modulus));
+                }
+            }
+            mont.from_form(y, modulus)
+        } else {
+            let mut y = base.clone();
+            checkpoints.push(base.clone());
+            for i in 1..=total {
+                if i % check_interval == 0 && cancelled.is_cancelled() {
+                    return empty();
+                }
+                Self::square_mod(&mut y, modulus);
+                if i % chunk == 0 && (i / chunk) < k {
+                    checkpoints.push(y.clone());
+                }
+            }
+            y
+        };
+        // Keep exactly the k bases B_0..B_{k-1}.
+        checkpoints.truncate(k as usize);
+
+        // p = hash_to_prime(x || y).
+        let modulus_bits = modulus.significant_bits();
+        let mut xy = base.clone();
+        xy <<= modulus_bits;
+        xy += &y_normal;
+        let p = match self.vdf.hash_to_prime(&xy) {
+            Ok(prime) => prime,
+            Err(_) => return empty(),
+        };
+
+        // q = ⌊2^T / p⌋, then split into the per-chunk exponents Q_j where
+        // q = Σ_j 2^(jL)·Q_j. Chunks 0..k-1 are exactly L bits; the last takes
+        // all remaining high bits so the lengths cover the full exponent. The
+        // shift width is range-checked rather than cast with `as u32`, which
+        // would silently truncate `T ≥ 2^32` to `1 << 0`; such a delay is
+        // infeasible, so a loud panic is the right failure.
+        let total_bits = u32::try_from(total).expect("delay exponent 2^T too large to materialize");
+        let q = (rug::Integer::from(1) << total_bits) / &p;
+        let mut exponents: Vec<rug::Integer> = Vec::with_capacity(k as usize);
+        let mask = (rug::Integer::from(1) << chunk as u32) - 1;
+        for j in 0..k {
+            let shifted = rug::Integer::from(&q >> (j * chunk) as u32);
+            if j == k - 1 {
+                exponents.push(shifted);
+            } else {
+                exponents.push(shifted & &mask);
+            }
+        }
+
+        // Dispatch one exponentiation per chunk and combine the partials.
+        let partials: Vec<Option<rug::Integer>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = checkpoints
+                .iter()
+                .zip(exponents.iter())
+                .map(|(b, e)| {
+                    scope.spawn(move || {
+                        if cancelled.is_cancelled() {
+                            return None;
+                        }
+                        let partial = match mont {
+                            Some(mont) => mont.pow_mod(b, e, modulus),
+                            None => b.clone().pow_mod(e, modulus).ok()?,
+                        };
+                        Some(partial)
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let mut pi = rug::Integer::from(1);
+        for partial in partials {
+            match partial {
+                Some(value) => {
+                    pi *= value;
+                    pi %= modulus;
+                }
+                None => return empty(),
+            }
+        }
+
+        Solution {
+            first: pi.to_digits::<u8>(rug::integer::Order::MsfBe),
+            second: y_normal.to_digits::<u8>(rug::integer::Order::MsfBe),
+        }
+    }
+
+    /// Cooperative variant of [`prove`](Self::prove) for async callers.
+    ///
+    /// It runs the same two squaring passes over the shared
+    /// [`square_mod`](Self::square_mod) / [`pi_step`](Self::pi_step) steps as the
+    /// blocking path, but yields to the executor and reports to `progress` once
+    /// every `check_interval` squarings, so a tokio runtime (or the cxx bridge
+    /// that feeds the node) can keep its reactor live, surface a percentage, and
+    /// cancel cleanly. Like [`prove`](Self::prove), cancellation returns an empty
+    /// [`Solution`]; the emitted bytes are identical to the blocking path.
+    pub async fn prove_async(
+        &self,
+        cancelled: &CancellationToken,
+        progress: Option<&dyn ProgressSink>,
+    ) -> Solution {
+        let modulus = self.vdf.modulus();
+        let base = self.vdf.base();
+
+        let empty = || Solution {
+            first: vec![],
+            second: vec![],
+        };
+
+        let total = match self.vdf.iterations().to_u64() {
+            Some(val) => val,
+            None => return empty(),
+        };
+        let check_interval = (total / 100).clamp(1, 10000);
+
+        // Pass 1: y = x^(2^T) mod N.
+        let mut y = base.clone();
+        for i in 1..=total {
+            Self::square_mod(&mut y, modulus);
+            if i % check_interval == 0 {
+                if cancelled.is_cancelled() {
+                    return empty();
+                }
+                if let Some(sink) = progress {
+                    sink.on_progress(i, total);
+                }
+                yield_once().await;
+            }
+        }
+
+        // p = hash_to_prime(x || y).
+        let modulus_bits = modulus.significant_bits();
+        let mut xy = base.clone();
+        xy <<= modulus_bits;
+        xy += &y;
+        let p = match self.vdf.hash_to_prime(&xy) {
+            Ok(prime) => prime,
+            Err(_) => return empty(),
+        };
+
+        // Pass 2: pi = x^(⌊2^T / p⌋) mod N via the shared long-division step.
+        let mut r = rug::Integer::from(1);
+        let mut pi = rug::Integer::from(1);
+        for i in 1..=total {
+            Self::pi_step(&mut pi, &mut r, &p, base, modulus);
+            if i % check_interval == 0 {
+                if cancelled.is_cancelled() {
+                    return empty();
+                }
+                if let Some(sink) = progress {
+                    sink.on_progress(total + i, total);
+                }
+                yield_once().await;
+            }
+        }
+
+        Solution {
+            first: pi.to_digits::<u8>(rug::integer::Order::MsfBe),
+            second: y.to_digits::<u8>(rug::integer::Order::MsfBe),
+        }
+    }
+
+    /// Montgomery-form proving for an odd modulus: both the `y` squaring chain
+    /// and the `pi` accumulation run on REDC multiplications, with the base and
+    /// the identity converted into Montgomery form once and the two outputs
+    /// converted back out once. Bit-identical to [`prove`](Self::prove)'s
+    /// remainder path, so it stays a drop-in default.
+    fn prove_montgomery(
+        &self,
+        mont: &crate::puzzle::MontgomeryCtx,
+        iterations_u64: u64,
+        check_interval: u64,
+        cancelled: &CancellationToken,
+    ) -> Solution {
+        let modulus = self.vdf.modulus(); // N
+        let base = self.vdf.base(); // x
+
+        let base_form = mont.to_form(base, modulus);
+
+        // y = x^(2^T) mod N, squared entirely in Montgomery form.
+        let mut y = base_form.clone();
+        for i in 1..=iterations_u64 {
+            if i % check_interval == 0 && cancelled.is_cancelled() {
+                return Solution {
+                    first: vec![],
+                    second: vec![],
+                };
+            }
+            y = mont.mul(&y, &y, modulus);
+        }
+        let y_normal = mont.from_form(y, modulus);
+
+        // p = hash_to_prime(x || y), computed on the normal-form output.
+        let modulus_bits = modulus.significant_bits();
+        let mut xy = base.clone();
+        xy <<= modulus_bits;
+        xy += &y_normal;
+        let p = match self.vdf.hash_to_prime(&xy) {
+            Ok(prime) => prime,
+            Err(_) => {
+                return Solution {
+                    first: vec![],
+                    second: vec![],
+                };
+            }
+        };
+
+        // pi = x^floor(2^T / p) mod N via the same streaming long division as
+        // `prove`, with the squaring and the conditional base multiply done in
+        // Montgomery form. `one` is the Montgomery representation of 1.
+        let mut r = rug::Integer::from(1);
+        let mut pi = mont.one(modulus);
+        for i in 1..=iterations_u64 {
+            if i % check_interval == 0 && cancelled.is_cancelled() {
+                return Solution {
+                    first: vec![],
+                    second: vec![],
+                };
+            }
+            pi = mont.mul(&pi, &pi, modulus);
+            r <<= 1;
+            if r >= p {
+                r -= &p;
+                pi = mont.mul(&pi, &base_form, modulus);
+            }
+        }
+        let pi_normal = mont.from_form(pi, modulus);
+
+        Solution {
+            first: pi_normal.to_digits::<u8>(rug::integer::Order::MsfBe),
+            second: y_normal.to_digits::<u8>(rug::integer::Order::MsfBe),
+        }
+    }
+
+    /// Fixed-width proving for an odd modulus that fits `L` limbs: both hot
+    /// loops run on the stack-allocated [`FixedMontgomery`] backend with no heap
+    /// allocation. The `hash_to_prime` step still runs through `rug`, and the
+    /// `(y, π)` pair is emitted byte-for-byte identically to
+    /// [`prove_montgomery`](Self::prove_montgomery), so this stays a transparent
+    /// backend swap selected by [`prove`](Self::prove) under `fixed-bigint`.
+    #[cfg(feature = "fixed-bigint")]
+    fn prove_fixed<const L: usize>(
+        &self,
+        iterations_u64: u64,
+        check_interval: u64,
+        cancelled: &CancellationToken,
+    ) -> Solution {
+        use crate::puzzle::fixed::{FixedMontgomery, FixedUint};
+
+        let modulus = self.vdf.modulus();
+        let base = self.vdf.base();
+        let empty = || Solution {
+            first: vec![],
+            second: vec![],
+        };
+
+        let n = FixedUint::<L>::from_rug(modulus);
+        let mont = match FixedMontgomery::<L>::new(&n) {
+            Some(mont) => mont,
+            None => return empty(),
+        };
+        let x = FixedUint::<L>::from_rug(base);
+
+        // y = x^(2^T) mod N on the limb backend.
+        let y = match mont.square_chain(&x, iterations_u64, check_interval, |_| {
+            cancelled.is_cancelled()
+        }) {
+            Some(y) => y,
+            None => return empty(),
+        };
+        let y_normal = y.to_rug();
+
+        // p = hash_to_prime(x || y), computed on the normal-form output.
+        let modulus_bits = modulus.significant_bits();
+        let mut xy = base.clone();
+        xy <<= modulus_bits;
+        xy += &y_normal;
+        let p = match self.vdf.hash_to_prime(&xy) {
+            Ok(prime) => prime,
+            Err(_) => return empty(),
+        };
+
+        // pi = x^floor(2^T / p) mod N, the long division run on the backend.
+        let pi = match mont.pi_chain(
+            &x,
+            &FixedUint::<L>::from_rug(&p),
+            iterations_u64,
+            check_interval,
+            |_| cancelled.is_cancelled(),
+        ) {
+            Some(pi) => pi,
+            None => return empty(),
+        };
+
+        Solution {
+            first: pi.to_rug().to_digits::<u8>(rug::integer::Order::MsfBe),
+            second: y_normal.to_digits::<u8>(rug::integer::Order::MsfBe),
+        }
+    }
+
+    /// Generates a segmented Wesolowski proof: a chain of `k` checkpoints a
+    /// verifier can check link by link, rejecting a bad proof after the first
+    /// wrong segment instead of re-running the whole delay.
+    ///
+    /// The total `T = 2^time_bits` squarings are split into `k` segments of
+    /// `delta = T / k` each, the final segment absorbing any remainder so the
+    /// lengths sum back to `T`. Starting from `x_0 = g` and carrying `x_i`
+    /// forward, each segment records the running value
+    /// `x_i = x_{i-1}^(2^delta) mod N` together with its proof element
+    /// `pi_i = x_{i-1}^floor(2^delta / l) mod N`, where
+    /// `l = hash_to_prime(x_{i-1} || x_i)`.
+    ///
+    /// Each checkpoint is a valid resume point: on cancellation the segments
+    /// produced so far are returned, so interrupted work can continue from the
+    /// last `x_i`. Returns an empty vector if `k` is zero or the delay does not
+    /// fit the machine-word squaring path.
+    pub fn prove_segmented(
+        &self,
+        cancelled: &CancellationToken,
+        k: u64,
+    ) -> Vec<NWesolowskiSegment> {
+        let modulus = self.vdf.modulus(); // N
+        let base = self.vdf.base(); // x
+
+        let total = match self.vdf.iterations().to_u64() {
+            Some(t) if t > 0 => t,
+            _ => return Vec::new(),
+        };
+        if k == 0 {
+            return Vec::new();
+        }
+        // Never emit more segments than there are squarings to attest.
+        let k = k.min(total);
+        let base_delta = total / k;
+        let remainder = total % k;
+
+        let modulus_bits = modulus.significant_bits();
+        let check_interval = (total / 100).clamp(1, 10000);
+
+        let mut segments = Vec::with_capacity(k as usize);
+        let mut x_prev = base.clone();
+
+        for seg in 0..k {
+            // The last segment absorbs the remainder so the lengths sum to T.
+            let delta = if seg == k - 1 {
+                base_delta + remainder
+            } else {
+                base_delta
+            };
+
+            // x_i = x_{i-1}^(2^delta) mod N by delta sequential squarings.
+            let mut x_i = x_prev.clone();
+            for i in 1..=delta {
+                if i % check_interval == 0 && cancelled.is_cancelled() {
+                    return segments;
+                }
+                Self::square_mod(&mut x_i, modulus);
+            }
+
+            // l = hash_to_prime(x_{i-1} || x_i).
+            let mut xy = x_prev.clone();
+            xy <<= modulus_bits;
+            xy += &x_i;
+            let l = match self.vdf.hash_to_prime(&xy) {
+                Ok(prime) => prime,
+                Err(_) => return segments,
+            };
+
+            // pi = x_{i-1}^floor(2^delta / l) mod N via the same streaming long
+            // division over the exponent used by `prove`.
+            let mut r = rug::Integer::from(1);
+            let mut pi = rug::Integer::from(1);
+            for i in 1..=delta {
+                if i % check_interval == 0 && cancelled.is_cancelled() {
+                    return segments;
+                }
+                Self::square_mod(&mut pi, modulus);
+                r <<= 1;
+                if r >= l {
+                    r -= &l;
+                    pi *= &x_prev;
+                    pi %= modulus;
+                }
+            }
+
+            segments.push(NWesolowskiSegment {
+                y: x_i.to_digits::<u8>(rug::integer::Order::MsfBe),
+                pi: pi.to_digits::<u8>(rug::integer::Order::MsfBe),
+                iterations: rug::Integer::from(delta),
+            });
+
+            // The segment output becomes the next segment's input.
+            x_prev = x_i;
+        }
+
+        segments
+    }
+
     // Fallback method for very large iteration counts that don't fit in u64
     fn prove_large_iterations(&self, cancelled: &CancellationToken) -> Solution {
         // Get puzzle parameters
@@ -209,7 +730,6 @@ impl<'a> WesolowskiProver<'a> {
 
 pub struct CancellationToken {
     flag: Arc<AtomicBool>,
-    external_ptr: Option<*const bool>,
 }
 
 impl Default for CancellationToken {
@@ -222,34 +742,158 @@ impl CancellationToken {
     pub fn new() -> Self {
         CancellationToken {
             flag: Arc::new(AtomicBool::new(false)),
-            external_ptr: None,
         }
     }
 
-    pub fn from_atomic_ptr(atomic_ptr: *const bool) -> Self {
-        CancellationToken {
-            flag: Arc::new(AtomicBool::new(false)), // Unused in this case
-            external_ptr: Some(atomic_ptr),
-        }
+    /// Builds a token backed by an externally-owned flag so another thread —
+    /// the async driver, a timeout task, or the cxx bridge feeding the node —
+    /// can request cancellation by flipping the same `Arc`. This replaces the
+    /// old raw-pointer mode, whose cross-FFI non-atomic write was unsound.
+    pub fn from_shared(flag: Arc<AtomicBool>) -> Self {
+        CancellationToken { flag }
+    }
+
+    /// Clones the shared flag so a caller can hand a cancel handle to another
+    /// task while keeping the token for the prover.
+    pub fn handle(&self) -> Arc<AtomicBool> {
+        self.flag.clone()
     }
 
     /// Signals cancellation to any listening operations
     pub fn cancel(&self) {
-        if let Some(ptr) = self.external_ptr {
-            unsafe {
-                *(ptr as *mut AtomicBool) = AtomicBool::new(true);
-            }
-        } else {
-            self.flag.store(true, Ordering::Release);
-        }
+        self.flag.store(true, Ordering::Release);
     }
 
     /// Checks if cancellation has been requested
     pub fn is_cancelled(&self) -> bool {
-        if let Some(ptr) = self.external_ptr {
-            unsafe { (*(ptr as *const AtomicBool)).load(Ordering::Acquire) }
-        } else {
-            self.flag.load(Ordering::Acquire)
+        self.flag.load(Ordering::Acquire)
+    }
+}
+
+/// Opaque handle identifying a proving job submitted to a [`ProverPool`].
+pub type JobId = u64;
+
+/// Per-job shared state: the cancellation token the worker honours and the
+/// finished solution once computed.
+struct Job {
+    cancel: Arc<CancellationToken>,
+    result: Option<Solution>,
+}
+
+/// A bounded worker-thread pool that runs Wesolowski proving off the caller's
+/// thread.
+///
+/// `prove` blocks for the full delay, which is awkward for the C++ node that
+/// wants to keep its reactor running. [`ProverPool`] lets the node `submit` a
+/// VDF and get back a [`JobId`] immediately, `poll` for the [`Solution`] later,
+/// and `cancel` a job through the atomic-backed cancellation path. Concurrency
+/// is capped by the number of worker threads.
+pub struct ProverPool {
+    sender: Option<Sender<Task>>,
+    workers: Vec<JoinHandle<()>>,
+    jobs: Arc<Mutex<HashMap<JobId, Job>>>,
+    next_id: AtomicU64,
+}
+
+/// Work item handed to a worker thread.
+struct Task {
+    id: JobId,
+    vdf: Arc<WesolowskiVdf>,
+}
+
+impl ProverPool {
+    /// Creates a pool backed by `num_threads` worker threads. At least one
+    /// worker is always spawned.
+    pub fn new(num_threads: usize) -> Self {
+        let num_threads = num_threads.max(1);
+        let (sender, receiver) = channel::<Task>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let jobs: Arc<Mutex<HashMap<JobId, Job>>> = Arc::new(Mutex::new(HashMap::new()));
+
+        let mut workers = Vec::with_capacity(num_threads);
+        for _ in 0..num_threads {
+            let receiver = receiver.clone();
+            let jobs = jobs.clone();
+            workers.push(std::thread::spawn(move || {
+                loop {
+                    // Pop one task; release the lock before proving so other
+                    // workers stay busy.
+                    let task = {
+                        let guard = receiver.lock().unwrap();
+                        guard.recv()
+                    };
+                    let Ok(task) = task else {
+                        break; // Sender dropped: pool is shutting down.
+                    };
+
+                    let cancel = {
+                        let guard = jobs.lock().unwrap();
+                        match guard.get(&task.id) {
+                            Some(job) => job.cancel.clone(),
+                            None => continue, // No bookkeeping entry for this id.
+                        }
+                    };
+
+                    let solution = WesolowskiProver::new(&task.vdf).prove(&cancel);
+
+                    if let Some(job) = jobs.lock().unwrap().get_mut(&task.id) {
+                        job.result = Some(solution);
+                    }
+                }
+            }));
+        }
+
+        ProverPool {
+            sender: Some(sender),
+            workers,
+            jobs,
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Enqueues `vdf` for proving and returns immediately with its [`JobId`].
+    pub fn submit(&self, vdf: WesolowskiVdf) -> JobId {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let job = Job {
+            cancel: Arc::new(CancellationToken::new()),
+            result: None,
+        };
+        self.jobs.lock().unwrap().insert(id, job);
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(Task {
+                id,
+                vdf: Arc::new(vdf),
+            });
+        }
+        id
+    }
+
+    /// Returns the [`Solution`] if the job has finished, taking it out of the
+    /// pool. Returns `None` while the job is still running or if the id is
+    /// unknown.
+    pub fn poll(&self, id: JobId) -> Option<Solution> {
+        let mut guard = self.jobs.lock().unwrap();
+        if guard.get(&id).and_then(|job| job.result.as_ref()).is_some() {
+            // Completed: hand the result back and drop the job bookkeeping.
+            return guard.remove(&id).and_then(|job| job.result);
+        }
+        None
+    }
+
+    /// Requests cancellation of a running job through its atomic-backed token.
+    pub fn cancel(&self, id: JobId) {
+        if let Some(job) = self.jobs.lock().unwrap().get(&id) {
+            job.cancel.cancel();
+        }
+    }
+}
+
+impl Drop for ProverPool {
+    fn drop(&mut self) {
+        // Dropping the sender closes the channel so workers exit their loop.
+        self.sender.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
         }
     }
 }
@@ -335,6 +979,216 @@ mod tests {
         // Note: y could theoretically be 1 in some cases, so we don't test for that
     }
 
+    #[test]
+    fn test_montgomery_prove_verifies() {
+        use crate::verifier::WesolowskiVerifier;
+
+        // Odd modulus -> Montgomery path. The emitted solution must satisfy the
+        // verification equation, confirming the REDC loops are correct.
+        let vdf = WesolowskiVdf::new(128, 8, vec![0x03], vec![0x01, 0x00, 0x01]);
+        assert!(vdf.montgomery().is_some());
+        let solution = WesolowskiProver::new(&vdf).prove(&CancellationToken::new());
+        assert!(WesolowskiVerifier::new(&vdf).verify(&solution));
+    }
+
+    #[cfg(feature = "fixed-bigint")]
+    #[test]
+    fn test_prove_fixed_matches_rug() {
+        // The fixed-width backend must emit a byte-identical solution to the
+        // GMP Montgomery path for an odd modulus.
+        let vdf = WesolowskiVdf::new(128, 8, vec![0x03], vec![0x01, 0x00, 0x01]);
+        let mont = vdf.montgomery().expect("odd modulus");
+        let prover = WesolowskiProver::new(&vdf);
+        let total = vdf.iterations().to_u64().unwrap();
+        let check_interval = (total / 100).clamp(1, 10000);
+
+        let fixed = prover.prove_fixed::<32>(total, check_interval, &CancellationToken::new());
+        let reference = prover.prove_montgomery(mont, total, check_interval, &CancellationToken::new());
+        assert_eq!(fixed.first, reference.first);
+        assert_eq!(fixed.second, reference.second);
+    }
+
+    #[test]
+    fn test_prove_parallel_matches_prove() {
+        // The parallel proof must be byte-identical to the sequential one across
+        // both the Montgomery (odd N) and the remainder (even N) paths, and for
+        // a thread count that does not divide the delay evenly.
+        let cases = [
+            (128u32, 8u32, vec![0x03u8], vec![0x01u8, 0x00, 0x01], 4usize),
+            (64, 6, vec![0x02u8], vec![0x01u8, 0x01], 3),
+            (128, 7, vec![0x02u8, 0x03], vec![0x02u8, 0x00], 5),
+        ];
+        for (lambda, time_bits, input, modulus, threads) in cases {
+            let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+            let prover = WesolowskiProver::new(&vdf);
+            let sequential = prover.prove(&CancellationToken::new());
+            let parallel = prover.prove_parallel(threads, &CancellationToken::new());
+            assert_eq!(sequential.first, parallel.first, "proof element mismatch");
+            assert_eq!(sequential.second, parallel.second, "output mismatch");
+        }
+    }
+
+    #[test]
+    fn test_prove_parallel_cancellation() {
+        // A pre-cancelled token yields an empty solution from the parallel path.
+        let vdf = WesolowskiVdf::new(128, 10, vec![0x02], vec![0x01, 0x01]);
+        let token = CancellationToken::new();
+        token.cancel();
+        let solution = WesolowskiProver::new(&vdf).prove_parallel(4, &token);
+        assert!(solution.first.is_empty());
+        assert!(solution.second.is_empty());
+    }
+
+    // Minimal executor for the async tests: `YieldOnce` wakes itself, so a
+    // busy-poll loop with a no-op waker drives the future to completion.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        use std::task::{RawWaker, RawWakerVTable, Waker};
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = Box::pin(fut);
+        loop {
+            if let Poll::Ready(value) = fut.as_mut().poll(&mut cx) {
+                return value;
+            }
+        }
+    }
+
+    #[test]
+    fn test_prove_async_matches_prove() {
+        // The async path must be byte-identical to the blocking path.
+        let vdf = WesolowskiVdf::new(128, 8, vec![0x03], vec![0x01, 0x00, 0x01]);
+        let prover = WesolowskiProver::new(&vdf);
+        let sequential = prover.prove(&CancellationToken::new());
+        let asynchronous = block_on(prover.prove_async(&CancellationToken::new(), None));
+        assert_eq!(sequential.first, asynchronous.first);
+        assert_eq!(sequential.second, asynchronous.second);
+    }
+
+    #[test]
+    fn test_prove_async_reports_progress() {
+        struct Counter(AtomicU64);
+        impl ProgressSink for Counter {
+            fn on_progress(&self, i: u64, total: u64) {
+                assert!(i > 0 && total > 0);
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let vdf = WesolowskiVdf::new(128, 8, vec![0x03], vec![0x01, 0x00, 0x01]);
+        let sink = Counter(AtomicU64::new(0));
+        let solution =
+            block_on(WesolowskiProver::new(&vdf).prove_async(&CancellationToken::new(), Some(&sink)));
+        assert!(!solution.first.is_empty());
+        assert!(sink.0.load(Ordering::Relaxed) > 0, "progress should fire");
+    }
+
+    #[test]
+    fn test_prove_async_cancellation() {
+        let vdf = WesolowskiVdf::new(128, 10, vec![0x02], vec![0x01, 0x01]);
+        let token = CancellationToken::new();
+        token.cancel();
+        let solution = block_on(WesolowskiProver::new(&vdf).prove_async(&token, None));
+        assert!(solution.first.is_empty());
+        assert!(solution.second.is_empty());
+    }
+
+    #[test]
+    fn test_prove_segmented_round_trip() {
+        use crate::verifier::WesolowskiVerifier;
+
+        let lambda = 128u32;
+        let time_bits = 6u32; // T = 64 squarings
+        let modulus = vec![0x01, 0x00, 0x01]; // 65537
+        let input = vec![0x03];
+
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let prover = WesolowskiProver::new(&vdf);
+        let segments = prover.prove_segmented(&CancellationToken::new(), 4);
+
+        // Four segments of 16 squarings that reconstruct the full delay.
+        assert_eq!(segments.len(), 4);
+        let total: u64 = segments
+            .iter()
+            .map(|s| s.iterations.to_u64().unwrap())
+            .sum();
+        assert_eq!(total, 64);
+
+        let verifier = WesolowskiVerifier::new(&vdf);
+        assert!(verifier.verify_chain(&segments));
+
+        // The final checkpoint equals the monolithic VDF output y.
+        let expected = prover.prove(&CancellationToken::new());
+        assert_eq!(segments.last().unwrap().y, expected.second);
+
+        // Tampering with a middle segment breaks the chain.
+        let mut tampered = segments;
+        tampered[1].pi[0] ^= 0xff;
+        assert!(!verifier.verify_chain(&tampered));
+    }
+
+    #[test]
+    fn test_prove_segmented_cancellation_returns_partial() {
+        let lambda = 128u32;
+        let time_bits = 10u32;
+        let modulus = vec![0x01, 0x01];
+        let input = vec![0x02];
+
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let prover = WesolowskiProver::new(&vdf);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        // Cancelled up front: no complete segment is produced.
+        assert!(prover.prove_segmented(&token, 8).is_empty());
+    }
+
+    #[test]
+    fn test_prover_pool_submit_and_poll() {
+        // A submitted job should eventually yield the same solution as a blocking prove.
+        let lambda = 128u32;
+        let time_bits = 4u32;
+        let modulus = vec![0x01, 0x01];
+        let input = vec![0x02];
+
+        let expected = {
+            let vdf = WesolowskiVdf::new(lambda, time_bits, input.clone(), modulus.clone());
+            WesolowskiProver::new(&vdf).prove(&CancellationToken::new())
+        };
+
+        let pool = ProverPool::new(2);
+        let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+        let id = pool.submit(vdf);
+
+        // Spin until the job completes.
+        let mut solution = None;
+        for _ in 0..10_000 {
+            if let Some(s) = pool.poll(id) {
+                solution = Some(s);
+                break;
+            }
+            std::thread::yield_now();
+        }
+
+        let solution = solution.expect("Job should complete");
+        assert_eq!(solution.first, expected.first);
+        assert_eq!(solution.second, expected.second);
+
+        // Polling a consumed job returns None.
+        assert!(pool.poll(id).is_none());
+    }
+
+    #[test]
+    fn test_prover_pool_cancel_unknown_job_is_noop() {
+        let pool = ProverPool::new(1);
+        pool.cancel(999); // Should not panic for an unknown id.
+        assert!(pool.poll(999).is_none());
+    }
+
     #[test]
     fn test_optimized_cancellation_intervals() {
         // Test that the optimized cancellation check intervals work correctly
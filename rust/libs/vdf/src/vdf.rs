@@ -5,6 +5,13 @@ pub struct Solution {
     pub second: Vec<u8>,
 }
 
+/// A Wesolowski proof bundling the delay output `y = g^(2^T) mod N` with the
+/// succinct proof element `π = g^q mod N`.
+pub struct WesolowskiProof {
+    pub y: rug::Integer,
+    pub pi: rug::Integer,
+}
+
 pub struct WesolowskiVdf {
     puzzle: RswPuzzle,
     hash: HashToPrime,
@@ -18,6 +25,22 @@ impl WesolowskiVdf {
         WesolowskiVdf { puzzle, hash }
     }
 
+    /// Like [`new`](Self::new) but with an explicit challenge-prime bit-length,
+    /// overriding the default `2·lambda` regime so the proof prime can be sized
+    /// directly for the target security level.
+    pub fn with_prime_bits(
+        lambda: u32,
+        time_bits: u32,
+        input: Vec<u8>,
+        modulus: Vec<u8>,
+        prime_bits: u32,
+    ) -> Self {
+        let puzzle = RswPuzzle::new(time_bits, &input, &modulus);
+        let hash = HashToPrime::with_prime_bits(lambda, prime_bits);
+
+        WesolowskiVdf { puzzle, hash }
+    }
+
     pub fn base(&self) -> &rug::Integer {
         self.puzzle.base()
     }
@@ -30,7 +53,190 @@ impl WesolowskiVdf {
         self.puzzle.modulus()
     }
 
+    /// The Montgomery-reduction constants for the modulus, or `None` for an
+    /// even/degenerate `N`. The prover uses these to run its squaring loops
+    /// without a per-step division.
+    pub fn montgomery(&self) -> Option<&crate::puzzle::MontgomeryCtx> {
+        self.puzzle.montgomery()
+    }
+
     pub fn hash_to_prime(&self, input: &rug::Integer) -> Result<rug::Integer, String> {
         self.hash.hash_to_prime(input)
     }
+
+    /// Computes the delay output `y = g^(2^T) mod N` by performing the `T`
+    /// sequential modular squarings that constitute the function's work.
+    pub fn eval(&self) -> rug::Integer {
+        self.puzzle.evaluate()
+    }
+
+    /// Produces a Wesolowski proof for the evaluated output.
+    ///
+    /// The Fiat–Shamir challenge prime `ℓ` is hashed from the
+    /// [`transcript`](Self::transcript) of `(g, y, N, T)`, the quotient
+    /// `q = ⌊2^T / ℓ⌋` is formed, and the proof element is `π = g^q mod N`.
+    pub fn prove(&self) -> Result<WesolowskiProof, String> {
+        let y = self.eval();
+        let l = self.hash_to_prime(&self.transcript(&y))?;
+        // q = ⌊2^T / ℓ⌋. `eval` squares `T = iterations()` times, so the delay
+        // exponent is `2^T`, matching `WesolowskiProver` and the verifier.
+        let t = self
+            .iterations()
+            .to_u32()
+            .ok_or_else(|| "delay exponent too large".to_string())?;
+        let two_pow_t = rug::Integer::from(1) << t;
+        let q = rug::Integer::from(&two_pow_t / &l);
+        let pi = self
+            .base()
+            .clone()
+            .pow_mod(&q, self.modulus())
+            .map_err(|_| "modular exponentiation failed".to_string())?;
+        Ok(WesolowskiProof { y, pi })
+    }
+
+    /// Verifies a `(y, π)` pair by recomputing the challenge prime and checking
+    /// `π^ℓ · g^r ≡ y (mod N)`, where `r = 2^T mod ℓ`.
+    pub fn verify(&self, y: &rug::Integer, pi: &rug::Integer) -> bool {
+        let modulus = self.modulus();
+        if y.is_zero() || pi.is_zero() || y >= modulus || pi >= modulus {
+            return false;
+        }
+
+        let l = match self.hash_to_prime(&self.transcript(y)) {
+            Ok(prime) => prime,
+            Err(_) => return false,
+        };
+
+        // r = 2^T mod ℓ, with the delay exponent `T = iterations()` (the number
+        // of squarings `eval` performs), computed by modular exponentiation of
+        // the base 2.
+        let r = match rug::Integer::from(2).pow_mod(self.iterations(), &l) {
+            Ok(result) => result,
+            Err(_) => return false,
+        };
+
+        let pi_l = match pi.clone().pow_mod(&l, modulus) {
+            Ok(result) => result,
+            Err(_) => return false,
+        };
+        let g_r = match self.base().clone().pow_mod(&r, modulus) {
+            Ok(result) => result,
+            Err(_) => return false,
+        };
+
+        let mut lhs = pi_l;
+        lhs *= g_r;
+        lhs %= modulus;
+        &lhs == y
+    }
+
+    /// Solves the VDF end to end, returning the public [`Solution`] `(π, y)`.
+    ///
+    /// This is the [`prove`](Self::prove) pipeline with the `(y, π)` pair encoded
+    /// into the two big-endian byte fields of [`Solution`]: `first` holds the
+    /// proof element `π` and `second` holds the output `y`, matching the layout
+    /// consumed by [`verify_solution`](Self::verify_solution). A hash-to-prime or
+    /// modexp failure yields an empty solution, which verification rejects.
+    pub fn solve(&self) -> Solution {
+        match self.prove() {
+            Ok(WesolowskiProof { y, pi }) => Solution {
+                first: pi.to_digits(rug::integer::Order::MsfBe),
+                second: y.to_digits(rug::integer::Order::MsfBe),
+            },
+            Err(_) => Solution {
+                first: Vec::new(),
+                second: Vec::new(),
+            },
+        }
+    }
+
+    /// Verifies a serialized [`Solution`] by decoding `(π, y)` and delegating to
+    /// [`verify`](Self::verify). Zero or out-of-range elements are rejected by
+    /// the underlying check.
+    pub fn verify_solution(&self, solution: &Solution) -> bool {
+        let pi = rug::Integer::from_digits(&solution.first, rug::integer::Order::MsfBe);
+        let y = rug::Integer::from_digits(&solution.second, rug::integer::Order::MsfBe);
+        self.verify(&y, &pi)
+    }
+
+    /// Builds the hash-to-prime transcript binding every public parameter so a
+    /// proof is not malleable across different `(g, y, N, T)` tuples.
+    ///
+    /// The parameters are packed into disjoint bit windows — `g`, `y` and `N`
+    /// each in a modulus-width window and `time_bits` (`log₂ T`) in the low 32
+    /// bits — so no `(g, y, N, T)` collides with another, pinning the Fiat–Shamir
+    /// challenge to exactly this instance.
+    fn transcript(&self, y: &rug::Integer) -> rug::Integer {
+        let modulus_bits = self.modulus().significant_bits();
+        let mut temp = self.base().clone();
+        temp <<= modulus_bits;
+        temp += y;
+        temp <<= modulus_bits;
+        temp += self.modulus();
+        temp <<= u32::BITS;
+        temp += self.puzzle.time_bits();
+        temp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prove_verify_round_trip() {
+        // Mirror the parameter sets exercised in the boundary tests.
+        let cases = [
+            (32u32, 6u32, vec![1u8], vec![7u8]),
+            (64, 8, vec![2u8, 3], vec![11u8, 13]),
+            (128, 10, vec![5u8, 7, 11], vec![17u8, 19, 23]),
+        ];
+        for (lambda, time_bits, input, modulus) in cases {
+            let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+            let proof = vdf.prove().expect("prove should succeed");
+            assert_eq!(proof.y, vdf.eval());
+            assert!(
+                vdf.verify(&proof.y, &proof.pi),
+                "round trip failed for lambda={lambda}, time_bits={time_bits}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve_verify_solution_round_trip() {
+        // Genuine solve → verify over several non-trivial (base, N) pairs: the
+        // public Solution must both verify and decode to the evaluated output.
+        let cases = [
+            (64u32, 8u32, vec![2u8, 3], vec![11u8, 13]),
+            (128, 10, vec![5u8, 7, 11], vec![17u8, 19, 23]),
+        ];
+        for (lambda, time_bits, input, modulus) in cases {
+            let vdf = WesolowskiVdf::new(lambda, time_bits, input, modulus);
+            let solution = vdf.solve();
+            assert!(!solution.first.is_empty());
+            assert!(!solution.second.is_empty());
+            assert!(vdf.verify_solution(&solution));
+
+            let y = rug::Integer::from_digits(&solution.second, rug::integer::Order::MsfBe);
+            assert_eq!(y, vdf.eval());
+        }
+    }
+
+    #[test]
+    fn test_verify_solution_rejects_empty() {
+        let vdf = WesolowskiVdf::new(64, 8, vec![2u8, 3], vec![11u8, 13]);
+        let empty = Solution {
+            first: Vec::new(),
+            second: Vec::new(),
+        };
+        assert!(!vdf.verify_solution(&empty));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_output() {
+        let vdf = WesolowskiVdf::new(64, 8, vec![2u8, 3], vec![11u8, 13]);
+        let proof = vdf.prove().unwrap();
+        let wrong_y = (proof.y.clone() + 1) % vdf.modulus();
+        assert!(!vdf.verify(&wrong_y, &proof.pi));
+    }
 }